@@ -1,4 +1,5 @@
 pub mod common;
 pub mod interactors;
 pub mod set;
+pub mod steal_job;
 pub mod user;
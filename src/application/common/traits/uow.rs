@@ -3,6 +3,7 @@ use async_trait::async_trait;
 use crate::application::{
     common::exceptions::{BeginError, CommitError, RollbackError},
     set::repository::SetRepo,
+    steal_job::repository::StealJobRepo,
     user::repository::UserRepo,
 };
 
@@ -20,6 +21,10 @@ pub trait UoW {
     where
         Self: 'a;
 
+    type StealJobRepo<'a>: StealJobRepo
+    where
+        Self: 'a;
+
     async fn connect(&mut self) -> Result<Self::Connection<'_>, BeginError>;
 
     async fn begin(&mut self) -> Result<(), BeginError>;
@@ -31,6 +36,8 @@ pub trait UoW {
     async fn user_repo(&mut self) -> Result<Self::UserRepo<'_>, BeginError>;
 
     async fn set_repo(&mut self) -> Result<Self::SetRepo<'_>, BeginError>;
+
+    async fn steal_job_repo(&mut self) -> Result<Self::StealJobRepo<'_>, BeginError>;
 }
 
 pub trait UoWFactory {
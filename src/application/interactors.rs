@@ -1,3 +1,10 @@
 pub mod create_set;
+pub mod create_steal_job;
 pub mod create_user;
 pub mod set_deleted_col;
+pub mod set_follow_source_col;
+pub mod update_set_title;
+pub mod update_source_sticker_ids;
+pub mod update_steal_job_progress;
+pub mod update_steal_job_short_name;
+pub mod update_sticker_count;
@@ -0,0 +1,35 @@
+use crate::application::{
+    common::{exceptions::TransactionKind, traits::uow::UoW as UoWTrait},
+    steal_job::{dto::create::Create, repository::StealJobRepo as _},
+};
+
+/// Returns id of the created job or `None`, if it could not be created
+pub async fn create_steal_job<'a, UoW>(
+    uow: &'a mut UoW,
+    job: Create<'a>,
+) -> Result<Option<i64>, TransactionKind>
+where
+    UoW: UoWTrait,
+{
+    let result = uow
+        .steal_job_repo()
+        .await
+        .map_err(TransactionKind::begin_err)?
+        .create(job)
+        .await;
+
+    let id = match result {
+        Ok(id) => id,
+        Err(_) => {
+            uow.rollback()
+                .await
+                .map_err(TransactionKind::rollback_err)?;
+
+            return Ok(None);
+        }
+    };
+
+    uow.commit().await.map_err(TransactionKind::commit_err)?;
+
+    Ok(Some(id))
+}
@@ -0,0 +1,41 @@
+use crate::application::{
+    common::{
+        exceptions::{RepoKind, TransactionKind},
+        traits::uow::UoW as UoWTrait,
+    },
+    set::{
+        dto::set_follow_source_col_by_short_name::SetFollowSourceColByShortName,
+        repository::SetRepo as _,
+    },
+};
+
+pub async fn set_follow_source_col<UoW>(
+    uow: &mut UoW,
+    set: SetFollowSourceColByShortName<'_>,
+) -> Result<(), TransactionKind>
+where
+    UoW: UoWTrait,
+{
+    let result = uow
+        .set_repo()
+        .await
+        .map_err(TransactionKind::begin_err)?
+        .set_follow_source_col_by_short_name(set)
+        .await;
+
+    match result {
+        Ok(_) => (),
+        Err(RepoKind::Unexpected(_)) => {
+            uow.rollback()
+                .await
+                .map_err(TransactionKind::rollback_err)?;
+        }
+        Err(RepoKind::Exception(_)) => {
+            return Ok(());
+        }
+    }
+
+    uow.commit().await.map_err(TransactionKind::commit_err)?;
+
+    Ok(())
+}
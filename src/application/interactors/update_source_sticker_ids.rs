@@ -0,0 +1,38 @@
+use crate::application::{
+    common::{
+        exceptions::{RepoKind, TransactionKind},
+        traits::uow::UoW as UoWTrait,
+    },
+    set::{dto::update_source_sticker_ids::UpdateSourceStickerIds, repository::SetRepo as _},
+};
+
+pub async fn update_source_sticker_ids<UoW>(
+    uow: &mut UoW,
+    set: UpdateSourceStickerIds<'_>,
+) -> Result<(), TransactionKind>
+where
+    UoW: UoWTrait,
+{
+    let result = uow
+        .set_repo()
+        .await
+        .map_err(TransactionKind::begin_err)?
+        .update_source_sticker_ids(set)
+        .await;
+
+    match result {
+        Ok(_) => (),
+        Err(RepoKind::Unexpected(_)) => {
+            uow.rollback()
+                .await
+                .map_err(TransactionKind::rollback_err)?;
+        }
+        Err(RepoKind::Exception(_)) => {
+            return Ok(());
+        }
+    }
+
+    uow.commit().await.map_err(TransactionKind::commit_err)?;
+
+    Ok(())
+}
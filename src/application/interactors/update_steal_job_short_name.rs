@@ -0,0 +1,38 @@
+use crate::application::{
+    common::{
+        exceptions::{RepoKind, TransactionKind},
+        traits::uow::UoW as UoWTrait,
+    },
+    steal_job::{dto::update_short_name::UpdateShortName, repository::StealJobRepo as _},
+};
+
+pub async fn update_steal_job_short_name<UoW>(
+    uow: &mut UoW,
+    job: UpdateShortName<'_>,
+) -> Result<(), TransactionKind>
+where
+    UoW: UoWTrait,
+{
+    let result = uow
+        .steal_job_repo()
+        .await
+        .map_err(TransactionKind::begin_err)?
+        .update_short_name(job)
+        .await;
+
+    match result {
+        Ok(_) => (),
+        Err(RepoKind::Unexpected(_)) => {
+            uow.rollback()
+                .await
+                .map_err(TransactionKind::rollback_err)?;
+        }
+        Err(RepoKind::Exception(_)) => {
+            return Ok(());
+        }
+    }
+
+    uow.commit().await.map_err(TransactionKind::commit_err)?;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use crate::application::{
+    common::{
+        exceptions::{RepoKind, TransactionKind},
+        traits::uow::UoW as UoWTrait,
+    },
+    set::{dto::update_sticker_count::UpdateStickerCount, repository::SetRepo as _},
+};
+
+pub async fn update_sticker_count<UoW>(
+    uow: &mut UoW,
+    set: UpdateStickerCount<'_>,
+) -> Result<(), TransactionKind>
+where
+    UoW: UoWTrait,
+{
+    let result = uow
+        .set_repo()
+        .await
+        .map_err(TransactionKind::begin_err)?
+        .update_sticker_count(set)
+        .await;
+
+    match result {
+        Ok(_) => (),
+        Err(RepoKind::Unexpected(_)) => {
+            uow.rollback()
+                .await
+                .map_err(TransactionKind::rollback_err)?;
+        }
+        Err(RepoKind::Exception(_)) => {
+            return Ok(());
+        }
+    }
+
+    uow.commit().await.map_err(TransactionKind::commit_err)?;
+
+    Ok(())
+}
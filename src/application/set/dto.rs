@@ -3,5 +3,10 @@ pub mod create;
 pub mod delete_by_short_name;
 pub mod get_all;
 pub mod get_by_short_name;
+pub mod get_by_source_short_name;
 pub mod get_by_tg_id;
 pub mod set_deleted_col_by_short_name;
+pub mod set_follow_source_col_by_short_name;
+pub mod update_source_sticker_ids;
+pub mod update_sticker_count;
+pub mod update_title;
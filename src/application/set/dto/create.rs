@@ -5,14 +5,27 @@ pub struct Create<'a> {
     tg_id: i64,
     short_name: &'a str,
     title: &'a str,
+    source_short_name: Option<&'a str>,
+    source_title: Option<&'a str>,
+    sticker_count: i32,
 }
 
 impl<'a> Create<'a> {
-    pub const fn new(tg_id: i64, short_name: &'a str, title: &'a str) -> Self {
+    pub const fn new(
+        tg_id: i64,
+        short_name: &'a str,
+        title: &'a str,
+        source_short_name: Option<&'a str>,
+        source_title: Option<&'a str>,
+        sticker_count: i32,
+    ) -> Self {
         Self {
             tg_id,
             short_name,
             title,
+            source_short_name,
+            source_title,
+            sticker_count,
         }
     }
 
@@ -27,4 +40,16 @@ impl<'a> Create<'a> {
     pub const fn title(&self) -> &'a str {
         self.title
     }
+
+    pub const fn source_short_name(&self) -> Option<&'a str> {
+        self.source_short_name
+    }
+
+    pub const fn source_title(&self) -> Option<&'a str> {
+        self.source_title
+    }
+
+    pub const fn sticker_count(&self) -> i32 {
+        self.sticker_count
+    }
 }
@@ -0,0 +1,24 @@
+use sqlx::prelude::FromRow;
+
+#[derive(Debug, Clone, PartialEq, Eq, FromRow)]
+pub struct GetBySourceShortName<'a> {
+    tg_id: i64,
+    source_short_name: &'a str,
+}
+
+impl<'a> GetBySourceShortName<'a> {
+    pub const fn new(tg_id: i64, source_short_name: &'a str) -> Self {
+        Self {
+            tg_id,
+            source_short_name,
+        }
+    }
+
+    pub const fn tg_id(&self) -> i64 {
+        self.tg_id
+    }
+
+    pub const fn source_short_name(&self) -> &'a str {
+        self.source_short_name
+    }
+}
@@ -0,0 +1,24 @@
+use sqlx::prelude::FromRow;
+
+#[derive(Debug, Clone, PartialEq, Eq, FromRow)]
+pub struct SetFollowSourceColByShortName<'a> {
+    short_name: &'a str,
+    follow_source: bool,
+}
+
+impl<'a> SetFollowSourceColByShortName<'a> {
+    pub const fn new(short_name: &'a str, follow_source: bool) -> Self {
+        Self {
+            short_name,
+            follow_source,
+        }
+    }
+
+    pub const fn short_name(&self) -> &'a str {
+        self.short_name
+    }
+
+    pub const fn follow_source(&self) -> bool {
+        self.follow_source
+    }
+}
@@ -0,0 +1,24 @@
+use sqlx::prelude::FromRow;
+
+#[derive(Debug, Clone, PartialEq, Eq, FromRow)]
+pub struct UpdateSourceStickerIds<'a> {
+    short_name: &'a str,
+    source_sticker_ids: &'a [String],
+}
+
+impl<'a> UpdateSourceStickerIds<'a> {
+    pub const fn new(short_name: &'a str, source_sticker_ids: &'a [String]) -> Self {
+        Self {
+            short_name,
+            source_sticker_ids,
+        }
+    }
+
+    pub const fn short_name(&self) -> &'a str {
+        self.short_name
+    }
+
+    pub const fn source_sticker_ids(&self) -> &'a [String] {
+        self.source_sticker_ids
+    }
+}
@@ -0,0 +1,22 @@
+use sqlx::prelude::FromRow;
+
+#[derive(Debug, Clone, PartialEq, Eq, FromRow)]
+pub struct UpdateStickerCount<'a> {
+    short_name: &'a str,
+    /// Number of added stickers, it's negative for the removed ones
+    delta: i32,
+}
+
+impl<'a> UpdateStickerCount<'a> {
+    pub const fn new(short_name: &'a str, delta: i32) -> Self {
+        Self { short_name, delta }
+    }
+
+    pub const fn short_name(&self) -> &'a str {
+        self.short_name
+    }
+
+    pub const fn delta(&self) -> i32 {
+        self.delta
+    }
+}
@@ -0,0 +1,21 @@
+use sqlx::prelude::FromRow;
+
+#[derive(Debug, Clone, PartialEq, Eq, FromRow)]
+pub struct UpdateTitle<'a> {
+    short_name: &'a str,
+    title: &'a str,
+}
+
+impl<'a> UpdateTitle<'a> {
+    pub const fn new(short_name: &'a str, title: &'a str) -> Self {
+        Self { short_name, title }
+    }
+
+    pub const fn short_name(&self) -> &'a str {
+        self.short_name
+    }
+
+    pub const fn title(&self) -> &'a str {
+        self.title
+    }
+}
@@ -11,8 +11,11 @@ use crate::{
 use super::{
     dto::{
         create::Create, delete_by_short_name::DeleteByShortName, get_all::GetAll,
-        get_by_short_name::GetByShortName, get_by_tg_id::GetByTgID,
-        set_deleted_col_by_short_name::SetDeletedColByShortName,
+        get_by_short_name::GetByShortName, get_by_source_short_name::GetBySourceShortName,
+        get_by_tg_id::GetByTgID, set_deleted_col_by_short_name::SetDeletedColByShortName,
+        set_follow_source_col_by_short_name::SetFollowSourceColByShortName,
+        update_source_sticker_ids::UpdateSourceStickerIds,
+        update_sticker_count::UpdateStickerCount, update_title::UpdateTitle,
     },
     exceptions::{SetShortNameAlreadyExist, SetShortNameNotExist, SetTgIdNotExist},
 };
@@ -44,7 +47,36 @@ pub trait SetRepo {
         set: SetDeletedColByShortName<'a>,
     ) -> Result<(), RepoKind<SetShortNameNotExist>>;
 
+    async fn set_follow_source_col_by_short_name<'a>(
+        &'a mut self,
+        set: SetFollowSourceColByShortName<'a>,
+    ) -> Result<(), RepoKind<SetShortNameNotExist>>;
+
+    async fn update_title<'a>(
+        &'a mut self,
+        set: UpdateTitle<'a>,
+    ) -> Result<(), RepoKind<SetShortNameNotExist>>;
+
+    async fn update_source_sticker_ids<'a>(
+        &'a mut self,
+        set: UpdateSourceStickerIds<'a>,
+    ) -> Result<(), RepoKind<SetShortNameNotExist>>;
+
+    async fn update_sticker_count<'a>(
+        &'a mut self,
+        set: UpdateStickerCount<'a>,
+    ) -> Result<(), RepoKind<SetShortNameNotExist>>;
+
     async fn get_all(&mut self, set: GetAll) -> Result<Vec<Set>, RepoError>;
 
+    /// Get all not deleted sticker sets of the user, that were stolen from the sticker set `source_short_name`
+    async fn get_by_source_short_name<'a>(
+        &'a mut self,
+        set: GetBySourceShortName<'a>,
+    ) -> Result<Vec<Set>, RepoError>;
+
+    /// Get all not deleted sticker sets, that follow their source sticker sets
+    async fn get_following_source(&mut self) -> Result<Vec<Set>, RepoError>;
+
     async fn count_by_tg_id(&mut self, set: CountByTgID) -> Result<i64, RepoError>;
 }
@@ -0,0 +1,4 @@
+pub mod create;
+pub mod get_all;
+pub mod update_progress;
+pub mod update_short_name;
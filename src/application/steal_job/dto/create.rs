@@ -0,0 +1,79 @@
+use telers::types::{PhotoSize, Sticker};
+
+use crate::domain::entities::steal_job::StealJobKind;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Create<'a> {
+    kind: StealJobKind,
+    tg_id: i64,
+    source_short_name: Option<&'a str>,
+    source_title: Option<&'a str>,
+    short_name: &'a str,
+    title: &'a str,
+    sticker_type: &'a str,
+    thumbnail: Option<&'a PhotoSize>,
+    stickers: &'a [Sticker],
+}
+
+impl<'a> Create<'a> {
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        kind: StealJobKind,
+        tg_id: i64,
+        source_short_name: Option<&'a str>,
+        source_title: Option<&'a str>,
+        short_name: &'a str,
+        title: &'a str,
+        sticker_type: &'a str,
+        thumbnail: Option<&'a PhotoSize>,
+        stickers: &'a [Sticker],
+    ) -> Self {
+        Self {
+            kind,
+            tg_id,
+            source_short_name,
+            source_title,
+            short_name,
+            title,
+            sticker_type,
+            thumbnail,
+            stickers,
+        }
+    }
+
+    pub const fn kind(&self) -> StealJobKind {
+        self.kind
+    }
+
+    pub const fn tg_id(&self) -> i64 {
+        self.tg_id
+    }
+
+    pub const fn source_short_name(&self) -> Option<&'a str> {
+        self.source_short_name
+    }
+
+    pub const fn source_title(&self) -> Option<&'a str> {
+        self.source_title
+    }
+
+    pub const fn short_name(&self) -> &'a str {
+        self.short_name
+    }
+
+    pub const fn title(&self) -> &'a str {
+        self.title
+    }
+
+    pub const fn sticker_type(&self) -> &'a str {
+        self.sticker_type
+    }
+
+    pub const fn thumbnail(&self) -> Option<&'a PhotoSize> {
+        self.thumbnail
+    }
+
+    pub const fn stickers(&self) -> &'a [Sticker] {
+        self.stickers
+    }
+}
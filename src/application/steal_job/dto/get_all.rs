@@ -0,0 +1,20 @@
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, PartialEq, Eq, FromRow)]
+pub struct GetAll {
+    /// If:
+    /// `None` -> get all
+    /// `Some(true)` -> get only finished
+    /// `Some(false)` -> get only NOT finished
+    get_finished: Option<bool>,
+}
+
+impl GetAll {
+    pub const fn new(get_finished: Option<bool>) -> Self {
+        Self { get_finished }
+    }
+
+    pub const fn get_finished(&self) -> Option<bool> {
+        self.get_finished
+    }
+}
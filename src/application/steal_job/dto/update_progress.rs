@@ -0,0 +1,36 @@
+use sqlx::prelude::FromRow;
+
+#[derive(Debug, Clone, PartialEq, Eq, FromRow)]
+pub struct UpdateProgress {
+    id: i64,
+    processed: i32,
+    failed: i32,
+    finished: bool,
+}
+
+impl UpdateProgress {
+    pub const fn new(id: i64, processed: i32, failed: i32, finished: bool) -> Self {
+        Self {
+            id,
+            processed,
+            failed,
+            finished,
+        }
+    }
+
+    pub const fn id(&self) -> i64 {
+        self.id
+    }
+
+    pub const fn processed(&self) -> i32 {
+        self.processed
+    }
+
+    pub const fn failed(&self) -> i32 {
+        self.failed
+    }
+
+    pub const fn finished(&self) -> bool {
+        self.finished
+    }
+}
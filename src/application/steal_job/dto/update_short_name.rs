@@ -0,0 +1,21 @@
+use sqlx::prelude::FromRow;
+
+#[derive(Debug, Clone, PartialEq, Eq, FromRow)]
+pub struct UpdateShortName<'a> {
+    id: i64,
+    short_name: &'a str,
+}
+
+impl<'a> UpdateShortName<'a> {
+    pub const fn new(id: i64, short_name: &'a str) -> Self {
+        Self { id, short_name }
+    }
+
+    pub const fn id(&self) -> i64 {
+        self.id
+    }
+
+    pub const fn short_name(&self) -> &'a str {
+        self.short_name
+    }
+}
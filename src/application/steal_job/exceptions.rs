@@ -0,0 +1,21 @@
+use std::borrow::Cow;
+
+use crate::application::common::exceptions::ApplicationException;
+
+#[derive(Debug, thiserror::Error)]
+#[error("steal job with id `{id}` not exists: {message}")]
+pub struct StealJobNotExist {
+    id: i64,
+    message: Cow<'static, str>,
+}
+
+impl StealJobNotExist {
+    pub fn new(id: i64, message: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            id,
+            message: message.into(),
+        }
+    }
+}
+
+impl ApplicationException for StealJobNotExist {}
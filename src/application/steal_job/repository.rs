@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+
+use crate::{
+    application::common::exceptions::{RepoError, RepoKind},
+    domain::entities::steal_job::StealJob,
+};
+
+use super::{
+    dto::{
+        create::Create, get_all::GetAll, update_progress::UpdateProgress,
+        update_short_name::UpdateShortName,
+    },
+    exceptions::StealJobNotExist,
+};
+
+#[async_trait]
+pub trait StealJobRepo {
+    /// Returns id of the created job
+    async fn create<'a>(&'a mut self, job: Create<'a>) -> Result<i64, RepoError>;
+
+    async fn update_progress(
+        &mut self,
+        job: UpdateProgress,
+    ) -> Result<(), RepoKind<StealJobNotExist>>;
+
+    async fn update_short_name<'a>(
+        &'a mut self,
+        job: UpdateShortName<'a>,
+    ) -> Result<(), RepoKind<StealJobNotExist>>;
+
+    async fn get_all(&mut self, job: GetAll) -> Result<Vec<StealJob>, RepoError>;
+}
@@ -1,4 +1,6 @@
 pub mod common;
 pub mod constants;
 pub mod stats;
+pub mod sticker_image;
 pub mod texts;
+pub mod token_bucket;
@@ -1,17 +1,134 @@
+use std::{collections::HashSet, hash::Hash};
+
 use random_string::generate;
-use telers::types::Sticker;
+use telers::types::{InputFile, InputSticker, Sticker};
+
+use super::constants::{
+    CREATE_CUSTOM_EMOJI_SET_IN_ONE_GO_LENGTH_LIMIT, CREATE_SET_IN_ONE_GO_LENGTH_LIMIT,
+    CUSTOM_EMOJI_STICKER_TYPE, MASK_STICKER_TYPE, MAX_CUSTOM_EMOJI_SET_LENGTH,
+    MAX_SET_TITLE_LENGTH, MAX_STICKER_EMOJI_LENGTH, MAX_STICKER_SET_LENGTH, REGULAR_STICKER_TYPE,
+    TELEGRAM_STICKER_SET_URL,
+};
+use crate::domain::entities::sticker::StickerFormat;
+
+/// Return format of the sticker set thumbnail by the path of its file:
+/// `.webp` and `.png` are static, `.tgs` is animated and `.webm` is video.
+pub fn thumbnail_format(file_path: &str) -> Option<StickerFormat> {
+    let (_, extension) = file_path.rsplit_once('.')?;
+
+    match extension.to_ascii_lowercase().as_str() {
+        "webp" | "png" | "jpg" | "jpeg" => Some(StickerFormat::Static),
+        "tgs" => Some(StickerFormat::Animated),
+        "webm" => Some(StickerFormat::Video),
+        _ => None,
+    }
+}
+
+/// Build `InputSticker` to add a copy of the `sticker` into the sticker set with `set_type`.
+/// Mask position is kept only for mask sticker sets, because other sticker sets can't have it.
+/// Telegram doesn't return keywords of the stickers, so they can't be copied.
+pub fn input_sticker(sticker: &Sticker, set_type: &str) -> InputSticker {
+    let input_sticker = InputSticker::new(
+        InputFile::id(sticker.file_id.as_ref()),
+        StickerFormat::from(sticker).as_str(),
+    )
+    .emoji_list(sticker.emoji.clone());
+
+    match sticker.mask_position.clone() {
+        Some(mask_position) if set_type == MASK_STICKER_TYPE => {
+            input_sticker.mask_position(mask_position)
+        }
+        _ => input_sticker,
+    }
+}
+
+/// Return title of the `part` of the sticker set, that is split into `parts_len` sticker sets: `Title (1/2)`.
+/// The title is shortened, if the result is longer than the limit of the sticker set title length.
+pub fn part_title(title: &str, part: usize, parts_len: usize) -> String {
+    let suffix = format!(" ({part}/{parts_len})");
 
-use super::constants::TELEGRAM_STICKER_SET_URL;
+    let mut end = title
+        .len()
+        .min(MAX_SET_TITLE_LENGTH.saturating_sub(suffix.len()));
+    while !title.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    format!("{}{suffix}", title[..end].trim_end())
+}
+
+/// Return stickers of the `source` sticker set, that its `copy` doesn't have, in their order.
+/// Stickers are compared by `file_unique_id`, because it's the same for the copies of a sticker.
+pub fn missing_stickers(
+    source: impl IntoIterator<Item = Sticker>,
+    copy: &[Sticker],
+) -> Vec<Sticker> {
+    let copy_stickers: HashSet<_> = copy.iter().map(|sticker| &sticker.file_unique_id).collect();
+
+    source
+        .into_iter()
+        .filter(|sticker| !copy_stickers.contains(&sticker.file_unique_id))
+        .collect()
+}
+
+/// Return type of the sticker set, that will be created from stickers of the sticker set with `sticker_type`
+pub fn stolen_sticker_type(sticker_type: &str) -> &'static str {
+    match sticker_type {
+        CUSTOM_EMOJI_STICKER_TYPE => CUSTOM_EMOJI_STICKER_TYPE,
+        MASK_STICKER_TYPE => MASK_STICKER_TYPE,
+        _ => REGULAR_STICKER_TYPE,
+    }
+}
+
+/// Return max number of stickers in the sticker set with specified `sticker_type`
+pub fn max_sticker_set_length(sticker_type: &str) -> usize {
+    if sticker_type == CUSTOM_EMOJI_STICKER_TYPE {
+        MAX_CUSTOM_EMOJI_SET_LENGTH
+    } else {
+        MAX_STICKER_SET_LENGTH
+    }
+}
 
-/// Return sticker format for each sticker.
-pub fn sticker_format(sticker: &Sticker) -> String {
-    if sticker.is_animated {
-        "animated".to_owned()
-    } else if sticker.is_video {
-        "video".to_owned()
+/// Return max number of stickers, that can be passed into `CreateNewStickerSet` for the sticker set with specified `sticker_type`
+pub fn create_set_in_one_go_length_limit(sticker_type: &str) -> usize {
+    if sticker_type == CUSTOM_EMOJI_STICKER_TYPE {
+        CREATE_CUSTOM_EMOJI_SET_IN_ONE_GO_LENGTH_LIMIT
     } else {
-        "static".to_owned()
+        CREATE_SET_IN_ONE_GO_LENGTH_LIMIT
+    }
+}
+
+/// Result of [`merge_unique`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Merged<T> {
+    pub items: Vec<T>,
+    /// Number of skipped items, that were already in the `items`
+    pub duplicates: usize,
+}
+
+/// Join `lists` in order into one list, skipping items with the same `key`
+pub fn merge_unique<T, K>(
+    lists: impl IntoIterator<Item = Vec<T>>,
+    key: impl Fn(&T) -> K,
+) -> Merged<T>
+where
+    K: Eq + Hash,
+{
+    let mut seen = HashSet::new();
+    let mut merged = Merged {
+        items: Vec::new(),
+        duplicates: 0,
+    };
+
+    for item in lists.into_iter().flatten() {
+        if !seen.insert(key(&item)) {
+            merged.duplicates += 1;
+        } else {
+            merged.items.push(item);
+        }
     }
+
+    merged
 }
 
 /// Generate new random sticker set name. This function assumes that the `length` field is a **positive integer greater than 2**!
@@ -29,6 +146,148 @@ pub fn generate_sticker_set_name_and_link(length: usize, bot_username: &str) ->
     (set_name, set_link)
 }
 
+/// Return `true` if `name` can be the short name of existing sticker set: it begins with a letter
+/// and contains only english letters, digits and underscores (no more than 64 characters)
+pub fn is_sticker_set_short_name(name: &str) -> bool {
+    name.len() <= 64
+        && name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Reasons why the name proposed by user can't be the short name of a new sticker set
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShortNameError {
+    NotBeginWithLetter,
+    InvalidCharacters,
+    ConsecutiveUnderscores,
+    TooLong,
+}
+
+impl ShortNameError {
+    pub const fn description(&self) -> &'static str {
+        match self {
+            ShortNameError::NotBeginWithLetter => "The name must begin with an english letter.",
+            ShortNameError::InvalidCharacters => {
+                "The name can contain only english letters, digits and underscores."
+            }
+            ShortNameError::ConsecutiveUnderscores => {
+                "The name can't contain consecutive underscores or end with an underscore."
+            }
+            ShortNameError::TooLong => "The name is too long.",
+        }
+    }
+}
+
+/// Make the short name of a new sticker set from the name proposed by user, following the Telegram rules:
+/// it begins with a letter, contains only english letters, digits and underscores (without consecutive ones),
+/// ends with `_by_<bot_username>` (it's added if user didn't write it) and isn't longer than 64 characters
+pub fn make_sticker_set_short_name(
+    name: &str,
+    bot_username: &str,
+) -> Result<String, ShortNameError> {
+    let suffix = format!("_by_{bot_username}");
+    let name = name.trim();
+
+    // user may write the name with the suffix
+    let name = match name.len().checked_sub(suffix.len()) {
+        Some(index)
+            if name.is_char_boundary(index) && name[index..].eq_ignore_ascii_case(&suffix) =>
+        {
+            &name[..index]
+        }
+        _ => name,
+    };
+
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return Err(ShortNameError::NotBeginWithLetter);
+    }
+    if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(ShortNameError::InvalidCharacters);
+    }
+    if name.contains("__") || name.ends_with('_') {
+        return Err(ShortNameError::ConsecutiveUnderscores);
+    }
+    if name.len() + suffix.len() > 64 {
+        return Err(ShortNameError::TooLong);
+    }
+
+    Ok(format!("{name}{suffix}"))
+}
+
+/// Return short names similar to `short_name` (that was made by `make_sticker_set_short_name`),
+/// that user can choose if `short_name` is already occupied
+pub fn sticker_set_short_name_alternatives(short_name: &str, bot_username: &str) -> Vec<String> {
+    let suffix = format!("_by_{bot_username}");
+    let name = short_name.strip_suffix(&suffix).unwrap_or(short_name);
+
+    (2..=6)
+        .map(|number| format!("{name}_{number}{suffix}"))
+        .chain([format!(
+            "{name}_{random}{suffix}",
+            random = generate(4, "abcdefghijklmnopqrstuvwxyz")
+        )])
+        .filter(|alternative| alternative.len() <= 64)
+        .collect()
+}
+
+/// Get sticker set short name from the text, that contains link to the sticker set (`t.me/addstickers/<name>`)
+/// or only its short name. Return `None` if there is no sticker set name in the text.
+pub fn parse_sticker_set_name(text: &str) -> Option<&str> {
+    let text = text.trim();
+
+    let set_name = match text.find(TELEGRAM_STICKER_SET_URL) {
+        Some(index) => text[index + TELEGRAM_STICKER_SET_URL.len()..]
+            .split(|c: char| c == '/' || c == '?' || c == '#' || c.is_whitespace())
+            .next()
+            .unwrap_or_default(),
+        None => text,
+    };
+
+    if is_sticker_set_short_name(set_name) {
+        Some(set_name)
+    } else {
+        None
+    }
+}
+
+/// Parse sticker numbers and ranges of them like `1-20, 35` into zero-based sticker indexes (sorted and without duplicates).
+/// Return `None` if the text contains something else or some number isn't in `1..=max`.
+pub fn parse_sticker_ranges(text: &str, max: usize) -> Option<Vec<usize>> {
+    let mut indexes = Vec::new();
+
+    for part in text
+        .split(',')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+    {
+        let (begin, end) = match part.split_once('-') {
+            Some((begin, end)) => (
+                begin.trim().parse::<usize>().ok()?,
+                end.trim().parse::<usize>().ok()?,
+            ),
+            None => {
+                let number = part.parse::<usize>().ok()?;
+                (number, number)
+            }
+        };
+
+        if begin == 0 || begin > end || end > max {
+            return None;
+        }
+
+        indexes.extend(begin - 1..end);
+    }
+
+    if indexes.is_empty() {
+        return None;
+    }
+
+    indexes.sort_unstable();
+    indexes.dedup();
+
+    Some(indexes)
+}
+
 /// Return begin and end of specify page, using the necessary information
 /// (more about the pages, etc. [here](../../bot_commands/handlers/my_stickers.rs)).
 pub fn get_page_begin_and_end(
@@ -57,6 +316,77 @@ pub fn set_created_by(set_name: &str, bot_username: &str) -> bool {
     false
 }
 
+/// Returns `true` if `char` is in the Unicode blocks of emoji (pictographs, symbols, dingbats, arrows and so on),
+/// so it can begin an emoji. Letters of any script aren't there.
+fn is_emoji_base(char: char) -> bool {
+    matches!(
+        char,
+        '\u{00A9}'
+            | '\u{00AE}'
+            | '\u{203C}'
+            | '\u{2049}'
+            | '\u{2122}'
+            | '\u{2139}'
+            | '\u{2194}'..='\u{21FF}'
+            | '\u{2300}'..='\u{23FF}'
+            | '\u{24C2}'
+            | '\u{25A0}'..='\u{27BF}'
+            | '\u{2900}'..='\u{297F}'
+            | '\u{2B00}'..='\u{2BFF}'
+            | '\u{3030}'
+            | '\u{303D}'
+            | '\u{3297}'
+            | '\u{3299}'
+            | '\u{1F000}'..='\u{1FAFF}'
+    )
+}
+
+/// Split `text` into separate emoji, e.g. `"😀 👍🏻👨‍👩‍👧"` into `["😀", "👍🏻", "👨‍👩‍👧"]`.
+/// Returns `None`, if there is something other than emoji in the text,
+/// or there are more emoji than a sticker can have.
+pub fn parse_emoji_list(text: &str) -> Option<Vec<String>> {
+    // characters, that are a part of the previous emoji: zero width joiner, variation selectors,
+    // skin tones, keycap and tags
+    let is_modifier = |char: char| matches!(char, '\u{200D}' | '\u{FE0E}' | '\u{FE0F}' | '\u{20E3}' | '\u{1F3FB}'..='\u{1F3FF}' | '\u{E0020}'..='\u{E007F}');
+    let is_regional_indicator = |char: char| matches!(char, '\u{1F1E6}'..='\u{1F1FF}');
+
+    let mut emoji_list: Vec<String> = Vec::new();
+    for char in text.chars().filter(|char| !char.is_whitespace()) {
+        let continues_previous = match emoji_list.last() {
+            Some(previous) => {
+                is_modifier(char)
+                    || previous.ends_with('\u{200D}')
+                    // flags are pairs of regional indicators
+                    || (is_regional_indicator(char)
+                        && previous.chars().count() == 1
+                        && previous.chars().all(is_regional_indicator))
+            }
+            None => false,
+        };
+
+        if continues_previous {
+            // only panic if `continues_previous` is `true` for empty list, but it's checked above
+            emoji_list.last_mut().unwrap().push(char);
+        } else {
+            emoji_list.push(char.to_string());
+        }
+    }
+
+    // emoji begin with a pictographic character, except keycaps like "1️⃣"
+    let is_emoji = |emoji: &String| {
+        emoji.chars().next().is_some_and(is_emoji_base) || emoji.ends_with('\u{20E3}')
+    };
+
+    if emoji_list.is_empty()
+        || emoji_list.len() > MAX_STICKER_EMOJI_LENGTH
+        || !emoji_list.iter().all(is_emoji)
+    {
+        return None;
+    }
+
+    Some(emoji_list)
+}
+
 #[test]
 fn sticker_format_test() {
     let (generated_name, generated_link) = generate_sticker_set_name_and_link(15, "your_bot");
@@ -84,3 +414,272 @@ fn get_page_begin_and_end_test() {
     assert_eq!(begin_index, 150);
     assert_eq!(end_index, 176);
 }
+
+#[test]
+fn parse_sticker_set_name_test() {
+    assert_eq!(parse_sticker_set_name("t.me/addstickers/Foo"), Some("Foo"));
+    assert_eq!(
+        parse_sticker_set_name("https://t.me/addstickers/Foo_by_bot"),
+        Some("Foo_by_bot")
+    );
+    assert_eq!(
+        parse_sticker_set_name("look at this: t.me/addstickers/Foo123/ cool"),
+        Some("Foo123")
+    );
+    assert_eq!(
+        parse_sticker_set_name("t.me/addstickers/Foo?x=1"),
+        Some("Foo")
+    );
+    assert_eq!(parse_sticker_set_name("  Foo  "), Some("Foo"));
+
+    assert_eq!(parse_sticker_set_name("t.me/addstickers/"), None);
+    assert_eq!(parse_sticker_set_name("1Foo"), None);
+    assert_eq!(parse_sticker_set_name("Foo bar"), None);
+    assert_eq!(parse_sticker_set_name("Foo-bar"), None);
+    assert_eq!(parse_sticker_set_name(&"a".repeat(65)), None);
+}
+
+#[test]
+fn sticker_set_length_limits_test() {
+    assert_eq!(max_sticker_set_length("regular"), MAX_STICKER_SET_LENGTH);
+    assert_eq!(max_sticker_set_length("mask"), MAX_STICKER_SET_LENGTH);
+    assert_eq!(
+        max_sticker_set_length("custom_emoji"),
+        MAX_CUSTOM_EMOJI_SET_LENGTH
+    );
+
+    assert_eq!(
+        create_set_in_one_go_length_limit("regular"),
+        CREATE_SET_IN_ONE_GO_LENGTH_LIMIT
+    );
+    assert_eq!(
+        create_set_in_one_go_length_limit("custom_emoji"),
+        CREATE_CUSTOM_EMOJI_SET_IN_ONE_GO_LENGTH_LIMIT
+    );
+}
+
+#[test]
+fn merge_unique_test() {
+    let merged = merge_unique(
+        [vec!["a", "b", "c"], vec!["b", "d"], vec!["a", "e", "f"]],
+        |item| *item,
+    );
+
+    assert_eq!(merged.items, vec!["a", "b", "c", "d", "e", "f"]);
+    assert_eq!(merged.duplicates, 2);
+
+    let merged = merge_unique([vec![1, 2], vec![3]], |item| *item);
+
+    assert_eq!(merged.items, vec![1, 2, 3]);
+    assert_eq!(merged.duplicates, 0);
+}
+
+#[test]
+fn parse_sticker_ranges_test() {
+    assert_eq!(parse_sticker_ranges("3", 5), Some(vec![2]));
+    assert_eq!(parse_sticker_ranges("1-3, 5", 5), Some(vec![0, 1, 2, 4]));
+    assert_eq!(parse_sticker_ranges(" 4 - 5 ,2,", 5), Some(vec![1, 3, 4]));
+    assert_eq!(parse_sticker_ranges("1-3, 2", 5), Some(vec![0, 1, 2]));
+
+    assert_eq!(parse_sticker_ranges("", 5), None);
+    assert_eq!(parse_sticker_ranges("0", 5), None);
+    assert_eq!(parse_sticker_ranges("6", 5), None);
+    assert_eq!(parse_sticker_ranges("3-1", 5), None);
+    assert_eq!(parse_sticker_ranges("1-", 5), None);
+    assert_eq!(parse_sticker_ranges("one", 5), None);
+}
+
+#[test]
+fn make_sticker_set_short_name_test() {
+    assert_eq!(
+        make_sticker_set_short_name("my_cats", "bot"),
+        Ok("my_cats_by_bot".to_owned())
+    );
+    assert_eq!(
+        make_sticker_set_short_name(" Cats2_by_Bot ", "bot"),
+        Ok("Cats2_by_bot".to_owned())
+    );
+
+    assert_eq!(
+        make_sticker_set_short_name("2cats", "bot"),
+        Err(ShortNameError::NotBeginWithLetter)
+    );
+    assert_eq!(
+        make_sticker_set_short_name("_cats", "bot"),
+        Err(ShortNameError::NotBeginWithLetter)
+    );
+    assert_eq!(
+        make_sticker_set_short_name("my-cats", "bot"),
+        Err(ShortNameError::InvalidCharacters)
+    );
+    assert_eq!(
+        make_sticker_set_short_name("my__cats", "bot"),
+        Err(ShortNameError::ConsecutiveUnderscores)
+    );
+    assert_eq!(
+        make_sticker_set_short_name("cats_", "bot"),
+        Err(ShortNameError::ConsecutiveUnderscores)
+    );
+    assert_eq!(
+        make_sticker_set_short_name(&"a".repeat(57), "bot"),
+        Err(ShortNameError::TooLong)
+    );
+    assert!(make_sticker_set_short_name(&"a".repeat(56), "bot").is_ok());
+
+    let alternatives = sticker_set_short_name_alternatives("cats_by_bot", "bot");
+    assert_eq!(alternatives[0], "cats_2_by_bot");
+    assert!(
+        alternatives
+            .iter()
+            .all(|alternative| alternative.starts_with("cats_") && alternative.ends_with("_by_bot"))
+    );
+}
+
+#[test]
+fn thumbnail_format_test() {
+    assert_eq!(
+        thumbnail_format("thumbnails/file_0.webp"),
+        Some(StickerFormat::Static)
+    );
+    assert_eq!(
+        thumbnail_format("thumbnails/file_1.PNG"),
+        Some(StickerFormat::Static)
+    );
+    assert_eq!(
+        thumbnail_format("thumbnails/file_2.tgs"),
+        Some(StickerFormat::Animated)
+    );
+    assert_eq!(
+        thumbnail_format("thumbnails/file_3.webm"),
+        Some(StickerFormat::Video)
+    );
+
+    assert_eq!(thumbnail_format("thumbnails/file_4"), None);
+    assert_eq!(thumbnail_format("thumbnails/file_5.mp4"), None);
+}
+
+#[test]
+fn input_sticker_test() {
+    let stickers: Vec<Sticker> = serde_json::from_str(
+        r#"[
+            {
+                "file_id": "mask_file_id",
+                "file_unique_id": "mask",
+                "type": "mask",
+                "width": 512,
+                "height": 512,
+                "is_animated": false,
+                "is_video": false,
+                "emoji": "😎",
+                "mask_position": { "point": "eyes", "x_shift": 0.5, "y_shift": -0.25, "scale": 1.5 }
+            },
+            {
+                "file_id": "regular_file_id",
+                "file_unique_id": "regular",
+                "type": "regular",
+                "width": 512,
+                "height": 512,
+                "is_animated": true,
+                "is_video": false,
+                "emoji": "🐱"
+            }
+        ]"#,
+    )
+    .unwrap();
+
+    let mask = input_sticker(&stickers[0], MASK_STICKER_TYPE);
+    assert!(mask.mask_position.is_some());
+    assert_eq!(mask.mask_position, stickers[0].mask_position);
+    assert_eq!(&*mask.format, "static");
+
+    // regular sticker sets can't have mask positions
+    let mask_in_regular_set = input_sticker(&stickers[0], REGULAR_STICKER_TYPE);
+    assert_eq!(mask_in_regular_set.mask_position, None);
+
+    let regular = input_sticker(&stickers[1], REGULAR_STICKER_TYPE);
+    assert_eq!(regular.mask_position, None);
+    assert_eq!(&*regular.format, "animated");
+
+    assert_eq!(stolen_sticker_type("regular"), REGULAR_STICKER_TYPE);
+    assert_eq!(stolen_sticker_type("mask"), MASK_STICKER_TYPE);
+    assert_eq!(
+        stolen_sticker_type("custom_emoji"),
+        CUSTOM_EMOJI_STICKER_TYPE
+    );
+}
+
+#[test]
+fn missing_stickers_test() {
+    let sticker = |file_unique_id: &str| -> Sticker {
+        serde_json::from_value(serde_json::json!({
+            "file_id": format!("{file_unique_id}_file_id"),
+            "file_unique_id": file_unique_id,
+            "type": "regular",
+            "width": 512,
+            "height": 512,
+            "is_animated": false,
+            "is_video": false
+        }))
+        .unwrap()
+    };
+
+    let source = vec![sticker("a"), sticker("b"), sticker("c"), sticker("d")];
+    let copy = vec![sticker("c"), sticker("a")];
+
+    let missing: Vec<_> = missing_stickers(source.clone(), &copy)
+        .into_iter()
+        .map(|sticker| sticker.file_unique_id)
+        .collect();
+    assert_eq!(missing, vec!["b".into(), "d".into()]);
+
+    assert!(missing_stickers(copy.clone(), &source).is_empty());
+    assert_eq!(missing_stickers(source.clone(), &[]).len(), source.len());
+}
+
+#[test]
+fn part_title_test() {
+    assert_eq!(part_title("Cats", 1, 2), "Cats (1/2)");
+    assert_eq!(part_title("Cats", 10, 12), "Cats (10/12)");
+
+    let long_title = "a".repeat(MAX_SET_TITLE_LENGTH);
+    let title = part_title(&long_title, 2, 3);
+    assert_eq!(title.len(), MAX_SET_TITLE_LENGTH);
+    assert!(title.ends_with("a (2/3)"));
+
+    // multibyte characters aren't cut in the middle
+    let long_title = "кот ".repeat(20);
+    let title = part_title(&long_title, 1, 2);
+    assert!(title.len() <= MAX_SET_TITLE_LENGTH);
+    assert!(title.ends_with("т (1/2)"));
+}
+
+#[test]
+fn parse_emoji_list_test() {
+    assert_eq!(
+        parse_emoji_list("😀 👍🏻👨‍👩‍👧"),
+        Some(vec!["😀".to_owned(), "👍🏻".to_owned(), "👨‍👩‍👧".to_owned()])
+    );
+    assert_eq!(
+        parse_emoji_list("🇺🇦🇯🇵 ❤️ 1️⃣"),
+        Some(vec![
+            "🇺🇦".to_owned(),
+            "🇯🇵".to_owned(),
+            "❤️".to_owned(),
+            "1️⃣".to_owned()
+        ])
+    );
+
+    assert_eq!(parse_emoji_list(""), None);
+    assert_eq!(parse_emoji_list("cat 🐱"), None);
+    assert_eq!(parse_emoji_list("кот"), None);
+    assert_eq!(parse_emoji_list("猫"), None);
+    assert_eq!(parse_emoji_list("🐱 猫"), None);
+    assert_eq!(
+        parse_emoji_list("™ ↩️ ⭐"),
+        Some(vec!["™".to_owned(), "↩️".to_owned(), "⭐".to_owned()])
+    );
+    assert_eq!(
+        parse_emoji_list(&"🐱".repeat(MAX_STICKER_EMOJI_LENGTH + 1)),
+        None
+    );
+}
@@ -1,6 +1,44 @@
 pub const STICKER_SETS_NUMBER_PER_PAGE: usize = 50;
+pub const STICKERS_NUMBER_PER_SELECT_PAGE: usize = 40;
+/// Number of sticker sets in one page of the keyboard to choose a sticker set to delete
+pub const DELETE_SETS_NUMBER_PER_PAGE: usize = 10;
 pub const MAX_STICKER_SET_LENGTH: usize = 120;
+pub const MAX_CUSTOM_EMOJI_SET_LENGTH: usize = 200;
 pub const TELEGRAM_STICKER_SET_URL: &str = "t.me/addstickers/";
+/// Files are downloaded by `{TELEGRAM_FILE_URL}<token>/<file_path>`
+pub const TELEGRAM_FILE_URL: &str = "https://api.telegram.org/file/bot";
+/// Telegram doesn't allow bots to download larger files (in bytes)
+pub const MAX_DOWNLOAD_FILE_SIZE: i64 = 20 * 1024 * 1024;
 pub const CREATE_SET_IN_ONE_GO_LENGTH_LIMIT: usize = 50;
-pub const MIN_SET_TITLE_LENGTH: usize = 64;
-pub const MAX_SET_TITLE_LENGTH: usize = 1;
+pub const CREATE_CUSTOM_EMOJI_SET_IN_ONE_GO_LENGTH_LIMIT: usize = 50;
+pub const REGULAR_STICKER_TYPE: &str = "regular";
+pub const CUSTOM_EMOJI_STICKER_TYPE: &str = "custom_emoji";
+pub const MASK_STICKER_TYPE: &str = "mask";
+pub const MIN_SET_TITLE_LENGTH: usize = 1;
+pub const MAX_SET_TITLE_LENGTH: usize = 64;
+/// Telegram allows up to 20 emoji for one sticker
+pub const MAX_STICKER_EMOJI_LENGTH: usize = 20;
+/// Emoji of the stickers made from images, if the user didn't write any in the caption
+pub const DEFAULT_STICKER_EMOJI: &str = "🖼";
+/// Longest side of the stickers made from images (in pixels)
+pub const STICKER_IMAGE_SIDE: u32 = 512;
+/// Fewest bits per color channel, that are kept while reducing colors of a too large sticker made from an image
+pub const STICKER_IMAGE_MIN_COLOR_BITS: u32 = 3;
+/// Documents with these types can be turned into stickers
+pub const STICKER_IMAGE_MIME_TYPES: [&str; 3] = ["image/png", "image/webp", "image/jpeg"];
+/// Telegram doesn't accept longer callback data of the inline keyboard buttons (in bytes)
+pub const MAX_CALLBACK_DATA_LENGTH: usize = 64;
+pub const JOB_QUEUE_CAPACITY: usize = 100;
+pub const WORKERS_NUMBER: usize = 4;
+/// How often sticker sets, that follow their source sticker sets, are checked for new stickers (in hours)
+pub const FOLLOW_SOURCE_CHECK_INTERVAL: i64 = 6;
+/// Limits of the requests scheduler. Telegram allows about 30 requests per second in total
+/// and about 1 request per second in one chat.
+pub const GLOBAL_REQUESTS_PER_SECOND: f64 = 30.0;
+pub const GLOBAL_REQUESTS_BURST: u32 = 30;
+pub const CHAT_REQUESTS_PER_SECOND: f64 = 1.0;
+pub const CHAT_REQUESTS_BURST: u32 = 3;
+/// How many times a request is retried, if Telegram asks us to wait (`retry_after`)
+pub const MAX_FLOOD_RETRIES: usize = 5;
+/// Above this number of chats, the buckets of the chats that are full again are dropped
+pub const MAX_CHAT_BUCKETS: usize = 10_000;
@@ -0,0 +1,149 @@
+use image::{
+    ExtendedColorType, ImageError, RgbaImage, codecs::webp::WebPEncoder, imageops::FilterType,
+};
+
+use super::constants::{
+    STICKER_IMAGE_MIME_TYPES, STICKER_IMAGE_MIN_COLOR_BITS, STICKER_IMAGE_SIDE,
+};
+use crate::domain::entities::sticker::StickerFormat;
+
+/// Static sticker made from an image
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StickerImage {
+    /// `.webp` file of the sticker
+    pub bytes: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum StickerImageError {
+    #[error("unsupported image: {0}")]
+    Image(#[from] ImageError),
+    #[error("the sticker file is too large ({size} bytes)")]
+    FileTooLarge { size: usize },
+}
+
+/// Returns `true` if the document with `mime_type` can be converted into a static sticker
+pub fn is_sticker_image_mime_type(mime_type: &str) -> bool {
+    STICKER_IMAGE_MIME_TYPES.contains(&mime_type)
+}
+
+/// Resize the image (PNG, WebP or JPEG) to 512 px on the longest side, keeping its aspect ratio,
+/// and encode it as `.webp` file of the static sticker.
+/// The image is encoded without loss, but if a detailed image (e.g. a photo) exceeds the sticker file size limit,
+/// its colors are reduced until it fits. The side isn't reduced, because it must be 512 px for the sticker.
+pub fn image_to_sticker(bytes: &[u8]) -> Result<StickerImage, StickerImageError> {
+    let image = image::load_from_memory(bytes)?
+        .resize(STICKER_IMAGE_SIDE, STICKER_IMAGE_SIDE, FilterType::Lanczos3)
+        .into_rgba8();
+    let (width, height) = image.dimensions();
+
+    let mut sticker = encode_webp(&image)?;
+    let mut color_bits = 8;
+
+    while sticker.len() as i64 > StickerFormat::Static.max_file_size()
+        && color_bits > STICKER_IMAGE_MIN_COLOR_BITS
+    {
+        color_bits -= 1;
+        sticker = encode_webp(&reduce_colors(image.clone(), color_bits))?;
+    }
+
+    if sticker.len() as i64 > StickerFormat::Static.max_file_size() {
+        return Err(StickerImageError::FileTooLarge {
+            size: sticker.len(),
+        });
+    }
+
+    Ok(StickerImage {
+        bytes: sticker,
+        width,
+        height,
+    })
+}
+
+fn encode_webp(image: &RgbaImage) -> Result<Vec<u8>, ImageError> {
+    let mut webp = Vec::new();
+    WebPEncoder::new_lossless(&mut webp).encode(
+        image.as_raw(),
+        image.width(),
+        image.height(),
+        ExtendedColorType::Rgba8,
+    )?;
+
+    Ok(webp)
+}
+
+/// Round the color channels to `bits` highest bits, so the image has less colors and compresses better.
+/// The alpha channel is kept as is to not spoil the edges of transparent images.
+fn reduce_colors(mut image: RgbaImage, bits: u32) -> RgbaImage {
+    let step = 1u16 << (8 - bits);
+
+    for pixel in image.pixels_mut() {
+        for channel in &mut pixel.0[..3] {
+            let rounded = (*channel as u16 + step / 2) / step * step;
+            *channel = rounded.min(255 - (255 % step)) as u8;
+        }
+    }
+
+    image
+}
+
+#[test]
+fn image_to_sticker_test() {
+    let fixture = |name: &str| {
+        std::fs::read(format!(
+            "{}/tests/fixtures/{name}",
+            env!("CARGO_MANIFEST_DIR")
+        ))
+        .unwrap()
+    };
+
+    // (fixture, width and height of the sticker)
+    let cases = [
+        ("landscape.png", (512, 341)),
+        ("portrait.jpg", (341, 512)),
+        ("small.webp", (512, 512)),
+        // too large without loss, so its colors are reduced
+        ("detailed.jpg", (512, 512)),
+    ];
+
+    for (name, (width, height)) in cases {
+        let sticker = image_to_sticker(&fixture(name)).unwrap();
+        assert_eq!((sticker.width, sticker.height), (width, height), "{name}");
+
+        let decoded = image::load_from_memory_with_format(&sticker.bytes, image::ImageFormat::WebP)
+            .unwrap()
+            .into_rgba8();
+        assert_eq!(decoded.dimensions(), (width, height), "{name}");
+        assert!(
+            sticker.bytes.len() as i64 <= StickerFormat::Static.max_file_size(),
+            "{name}"
+        );
+    }
+
+    // transparency of PNG is kept
+    let sticker = image_to_sticker(&fixture("landscape.png")).unwrap();
+    let decoded = image::load_from_memory(&sticker.bytes)
+        .unwrap()
+        .into_rgba8();
+    assert_eq!(decoded.get_pixel(0, 0)[3], 255);
+    assert_eq!(decoded.get_pixel(511, 0)[3], 0);
+
+    let reduced = reduce_colors(
+        RgbaImage::from_pixel(1, 1, image::Rgba([7, 200, 255, 100])),
+        3,
+    );
+    assert_eq!(reduced.get_pixel(0, 0).0, [0, 192, 224, 100]);
+
+    assert!(matches!(
+        image_to_sticker(b"definitely not an image"),
+        Err(StickerImageError::Image(_))
+    ));
+
+    assert!(is_sticker_image_mime_type("image/png"));
+    assert!(is_sticker_image_mime_type("image/webp"));
+    assert!(is_sticker_image_mime_type("image/jpeg"));
+    assert!(!is_sticker_image_mime_type("image/gif"));
+    assert!(!is_sticker_image_mime_type("video/webm"));
+}
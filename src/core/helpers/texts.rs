@@ -1,5 +1,6 @@
 //! Its bad to storing messages here, but i dont care
 
+use chrono::{DateTime, Utc};
 use telers::utils::text::{html_bold, html_code, html_quote, html_text_link};
 
 use super::{common::get_page_begin_and_end, constants::TELEGRAM_STICKER_SET_URL};
@@ -12,23 +13,86 @@ pub fn default_error_message() -> String {
     "Sorry, an error occurred".to_string()
 }
 
+/// Return text like `stolen from <source> on <date>`, using everything we know about the sticker set source.
+/// If `source_short_name` is specified, the source is a link.
+pub fn provenance_message(
+    source_title: Option<&str>,
+    source_short_name: Option<&str>,
+    created_at: Option<DateTime<Utc>>,
+) -> Option<String> {
+    let source = match (source_title, source_short_name) {
+        (Some(title), Some(short_name)) => Some(html_text_link(
+            html_quote(title),
+            format!("{TELEGRAM_STICKER_SET_URL}{short_name}"),
+        )),
+        (None, Some(short_name)) => Some(html_text_link(
+            html_quote(short_name),
+            format!("{TELEGRAM_STICKER_SET_URL}{short_name}"),
+        )),
+        (Some(title), None) => Some(html_quote(title)),
+        (None, None) => None,
+    };
+    let date = created_at.map(|created_at| created_at.format("%Y-%m-%d").to_string());
+
+    match (source, date) {
+        (Some(source), Some(date)) => Some(format!("stolen from {source} on {date}")),
+        (Some(source), None) => Some(format!("stolen from {source}")),
+        (None, Some(date)) => Some(format!("stolen on {date}")),
+        (None, None) => None,
+    }
+}
+
 pub fn sticker_set_message(
     sticker_set_title: &str,
     sticker_set_name: &str,
     sticker_set_link: &str,
+    provenance: Option<&str>,
 ) -> String {
     format!(
         "
-        Now you have your own sticker pack {new_ss_url}. \
+        Now you have your own sticker pack {new_ss_url}{provenance}. \
         If you want, you can add any stickers to this pack using /addstickers. \
         You can also manage your new sticker pack, using official Telegram bot @Stickers, and to do it, \
         you should use this internal name of your sticker pack: {sticker_set_name}
         ",
         new_ss_url = html_text_link(html_quote(sticker_set_title), sticker_set_link),
+        provenance = provenance
+            .map(|provenance| format!(" ({provenance})"))
+            .unwrap_or_default(),
         sticker_set_name = html_code(sticker_set_name)
     )
 }
 
+/// Message about the sticker set, that was split into several sticker sets, because there were too many stickers.
+/// Each part is `(title, link, number of stickers that could not be added)`.
+pub fn split_sticker_sets_message(parts: &[(&str, &str, i32)], provenance: Option<&str>) -> String {
+    let mut text = format!(
+        "There were too many stickers for one sticker pack, so I split them into {parts_len} sticker packs",
+        parts_len = parts.len()
+    );
+    if let Some(provenance) = provenance {
+        text.push_str(&format!(" ({provenance})"));
+    }
+    text.push(':');
+
+    for (index, (title, link, failed)) in parts.iter().enumerate() {
+        text.push_str(&format!(
+            "\n{number}. {link}",
+            number = index + 1,
+            link = html_text_link(html_quote(*title), *link)
+        ));
+        if *failed > 0 {
+            text.push_str(&format!(
+                " (due to an error, {failed} sticker(s) have not been stolen)",
+                failed = html_code(failed.to_string())
+            ));
+        }
+    }
+
+    text.push_str("\n\nIf you want, you can add any stickers to these packs using /addstickers.");
+    text
+}
+
 pub fn start_message(username: &str) -> String {
     format!(
         "
@@ -36,6 +100,16 @@ pub fn start_message(username: &str) -> String {
     List of commands you can use:\n\n\
     /stealpack - Steal sticker pack\n\
     /addstickers - Add stickers to a sticker pack stolen by me\n\
+    /newpack - Create a new sticker pack from images\n\
+    /merge - Merge several sticker packs into one\n\
+    /follow - Add new stickers of the source pack into your stolen pack\n\
+    /removesticker - Remove stickers from a sticker pack stolen by me\n\
+    /reorder - Move stickers inside a sticker pack stolen by me\n\
+    /setemoji - Change emoji of stickers in a sticker pack stolen by me\n\
+    /rename - Rename a sticker pack stolen by me\n\
+    /deletepack - Delete a sticker pack stolen by me\n\
+    /claim - Add a sticker pack made by me, but missing from your list\n\
+    /export - Download a sticker pack as ZIP archive\n\
     /mystickers - List of your stolen stickers\n\
     /stats - See the bot statistics\n\n\
     /help - Show this message\n\
@@ -75,6 +149,13 @@ pub fn current_page_message(
         let sticker_set = html_text_link(html_quote(sticker_set_title), sticker_set_link);
 
         sticker_sets_page.push_str(&sticker_set);
+
+        // without the source link, because the list can be long
+        if let Some(provenance) =
+            provenance_message(set.source_title.as_deref(), None, set.created_at)
+        {
+            sticker_sets_page.push_str(&format!(" ({provenance})"));
+        }
     }
 
     sticker_sets_page
@@ -136,6 +217,16 @@ fn current_page_message_test() {
             short_name: format!("short_name{i}"),
             deleted: false,
             title: format!("title{i}"),
+            source_short_name: None,
+            follow_source: false,
+            source_title: (i == 0).then(|| "source0".to_owned()),
+            created_at: (i == 0).then(|| {
+                DateTime::parse_from_rfc3339("2026-10-18T12:00:00Z")
+                    .unwrap()
+                    .with_timezone(&Utc)
+            }),
+            sticker_count: None,
+            source_sticker_ids: None,
         });
     }
 
@@ -144,11 +235,48 @@ fn current_page_message_test() {
     assert_eq!(
         message.as_str(),
         "List of your stickers (1 page):\n\
-        <a href=\"t.me/addstickers/short_name0\">title0</a> \
+        <a href=\"t.me/addstickers/short_name0\">title0</a> (stolen from source0 on 2026-10-18) \
         | <a href=\"t.me/addstickers/short_name1\">title1</a> \
         | <a href=\"t.me/addstickers/short_name2\">title2</a> \
         | <a href=\"t.me/addstickers/short_name3\">title3</a> \
-        | <a href=\"t.me/addstickers/short_name4\">title4</a> \
-        | "
+        | <a href=\"t.me/addstickers/short_name4\">title4</a>"
     );
 }
+
+#[test]
+fn provenance_message_test() {
+    let created_at = DateTime::parse_from_rfc3339("2026-10-18T12:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc);
+
+    assert_eq!(
+        provenance_message(Some("Cats"), Some("cats"), Some(created_at)).as_deref(),
+        Some("stolen from <a href=\"t.me/addstickers/cats\">Cats</a> on 2026-10-18")
+    );
+    assert_eq!(
+        provenance_message(Some("Cats"), None, None).as_deref(),
+        Some("stolen from Cats")
+    );
+    assert_eq!(
+        provenance_message(None, None, Some(created_at)).as_deref(),
+        Some("stolen on 2026-10-18")
+    );
+    assert_eq!(provenance_message(None, None, None), None);
+}
+
+#[test]
+fn split_sticker_sets_message_test() {
+    let text = split_sticker_sets_message(
+        &[
+            ("Cats (1/2)", "https://t.me/addstickers/cats_1", 0),
+            ("Cats (2/2)", "https://t.me/addstickers/cats_2", 3),
+        ],
+        Some("stolen on 2024-01-01"),
+    );
+
+    assert!(text.contains("into 2 sticker packs (stolen on 2024-01-01):"));
+    assert!(text.contains("1. <a href=\"https://t.me/addstickers/cats_1\">Cats (1/2)</a>\n"));
+    assert!(text.contains(
+        "2. <a href=\"https://t.me/addstickers/cats_2\">Cats (2/2)</a> (due to an error, <code>3</code>"
+    ));
+}
@@ -0,0 +1,117 @@
+use std::time::{Duration, Instant};
+
+/// Classic token bucket: it holds up to `capacity` tokens and gets `refill_per_second` new tokens every second.
+/// Each request takes one token, so requests can go in bursts of `capacity`, but not faster than the refill rate in the long run.
+#[derive(Debug, Clone)]
+pub struct TokenBucket {
+    capacity: f64,
+    refill_per_second: f64,
+    tokens: f64,
+    last_refill: Instant,
+    /// The bucket gives no tokens until this time (Telegram asked us to wait)
+    paused_until: Option<Instant>,
+}
+
+impl TokenBucket {
+    /// Create a full bucket
+    pub fn new(capacity: u32, refill_per_second: f64, now: Instant) -> Self {
+        Self {
+            capacity: capacity as f64,
+            refill_per_second,
+            tokens: capacity as f64,
+            last_refill: now,
+            paused_until: None,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+
+        self.tokens = (self.tokens + elapsed * self.refill_per_second).min(self.capacity);
+        self.last_refill = now;
+    }
+
+    /// Returns how long to wait until the bucket has a token, or `None` if it has one right now
+    pub fn wait_time(&mut self, now: Instant) -> Option<Duration> {
+        if let Some(paused_until) = self.paused_until {
+            if paused_until > now {
+                return Some(paused_until - now);
+            }
+            self.paused_until = None;
+            // tokens aren't accumulated during the pause
+            self.last_refill = paused_until;
+        }
+
+        self.refill(now);
+
+        if self.tokens >= 1.0 {
+            None
+        } else {
+            Some(Duration::from_secs_f64(
+                (1.0 - self.tokens) / self.refill_per_second,
+            ))
+        }
+    }
+
+    /// Take one token. Should be called only after `wait_time` returned `None`
+    pub fn take(&mut self) {
+        self.tokens = (self.tokens - 1.0).max(0.0);
+    }
+
+    /// Take all the tokens and don't give new ones until `until`
+    pub fn pause(&mut self, until: Instant) {
+        self.tokens = 0.0;
+        self.paused_until = Some(self.paused_until.map_or(until, |paused| paused.max(until)));
+    }
+
+    /// Returns `true` if the bucket is full, so it behaves the same as a new one
+    pub fn is_full(&mut self, now: Instant) -> bool {
+        self.wait_time(now).is_none() && self.tokens >= self.capacity
+    }
+}
+
+#[test]
+fn token_bucket_test() {
+    let now = Instant::now();
+    let mut bucket = TokenBucket::new(2, 1.0, now);
+
+    assert!(bucket.is_full(now));
+
+    // burst of `capacity` requests
+    assert_eq!(bucket.wait_time(now), None);
+    bucket.take();
+    assert_eq!(bucket.wait_time(now), None);
+    bucket.take();
+    assert_eq!(bucket.wait_time(now), Some(Duration::from_secs(1)));
+
+    let later = now + Duration::from_millis(500);
+    assert_eq!(bucket.wait_time(later), Some(Duration::from_millis(500)));
+
+    let later = now + Duration::from_secs(1);
+    assert_eq!(bucket.wait_time(later), None);
+    bucket.take();
+
+    // no more tokens than capacity
+    let later = now + Duration::from_secs(60);
+    assert!(bucket.is_full(later));
+
+    // pause empties the bucket and no tokens are given during it
+    bucket.pause(later + Duration::from_secs(10));
+    assert_eq!(
+        bucket.wait_time(later + Duration::from_secs(4)),
+        Some(Duration::from_secs(6))
+    );
+    // shorter pause doesn't cancel the longer one
+    bucket.pause(later + Duration::from_secs(5));
+    assert_eq!(
+        bucket.wait_time(later + Duration::from_secs(4)),
+        Some(Duration::from_secs(6))
+    );
+    assert_eq!(
+        bucket.wait_time(later + Duration::from_secs(10)),
+        Some(Duration::from_secs(1))
+    );
+    assert_eq!(bucket.wait_time(later + Duration::from_secs(11)), None);
+}
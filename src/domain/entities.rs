@@ -1,2 +1,4 @@
 pub mod set;
+pub mod steal_job;
+pub mod sticker;
 pub mod user;
@@ -1,7 +1,22 @@
+use chrono::{DateTime, Utc};
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Set {
     pub tg_id: i64,
     pub short_name: String,
     pub deleted: bool,
     pub title: String,
+    /// Short name of the sticker set, from which this sticker set was stolen
+    pub source_short_name: Option<String>,
+    /// If `true`, new stickers of the source sticker set are added into this sticker set
+    pub follow_source: bool,
+    pub source_title: Option<String>,
+    /// When the sticker set was stolen. It's `None` for sticker sets stolen before we started to save it.
+    pub created_at: Option<DateTime<Utc>>,
+    /// Number of stickers in the sticker set, it's updated when the bot adds or removes them.
+    /// It's `None` for the sticker sets stolen before it was saved.
+    pub sticker_count: Option<i32>,
+    /// `file_unique_id`s of the stickers of the source sticker set, that are already known to this sticker set.
+    /// It's `None` if the sticker set doesn't follow the source.
+    pub source_sticker_ids: Option<Vec<String>>,
 }
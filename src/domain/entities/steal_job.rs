@@ -0,0 +1,52 @@
+use telers::types::{PhotoSize, Sticker};
+
+/// What the job does with its stickers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StealJobKind {
+    /// Create a new sticker set from the stickers
+    Steal,
+    /// Add the stickers into the existing user's sticker set
+    AddStickers,
+}
+
+impl StealJobKind {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            StealJobKind::Steal => "steal",
+            StealJobKind::AddStickers => "add_stickers",
+        }
+    }
+}
+
+impl From<&str> for StealJobKind {
+    fn from(kind: &str) -> Self {
+        match kind {
+            "add_stickers" => StealJobKind::AddStickers,
+            _ => StealJobKind::Steal,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StealJob {
+    pub id: i64,
+    pub kind: StealJobKind,
+    pub tg_id: i64,
+    /// Source of the stickers, jobs of `AddStickers` kind don't have it
+    pub source_short_name: Option<String>,
+    pub source_title: Option<String>,
+    pub short_name: String,
+    pub title: String,
+    /// Type of the sticker set `short_name`: `regular`, `mask` or `custom_emoji`
+    pub sticker_type: String,
+    /// Thumbnail of the source sticker set, that is copied to the sticker set `short_name`
+    pub thumbnail: Option<PhotoSize>,
+    /// Stickers that should be added to the sticker set `short_name`, in order
+    pub stickers: Vec<Sticker>,
+    /// Number of stickers from `stickers` that have already been processed.
+    /// If it's `0` for the job of `Steal` kind, the sticker set `short_name` is not created yet
+    pub processed: i32,
+    /// Number of processed stickers that could not be added
+    pub failed: i32,
+    pub finished: bool,
+}
@@ -0,0 +1,244 @@
+use telers::types::Sticker;
+
+use crate::core::helpers::constants::{
+    CUSTOM_EMOJI_STICKER_TYPE, MASK_STICKER_TYPE, REGULAR_STICKER_TYPE,
+};
+
+/// Format of the sticker file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StickerFormat {
+    /// `.webp` or `.png` image
+    Static,
+    /// `.tgs` animation
+    Animated,
+    /// `.webm` video
+    Video,
+}
+
+impl StickerFormat {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            StickerFormat::Static => "static",
+            StickerFormat::Animated => "animated",
+            StickerFormat::Video => "video",
+        }
+    }
+
+    /// Max size of the sticker file in bytes
+    pub const fn max_file_size(self) -> i64 {
+        match self {
+            StickerFormat::Static => 512 * 1024,
+            StickerFormat::Animated => 64 * 1024,
+            StickerFormat::Video => 256 * 1024,
+        }
+    }
+}
+
+impl From<&Sticker> for StickerFormat {
+    fn from(sticker: &Sticker) -> Self {
+        if sticker.is_animated {
+            StickerFormat::Animated
+        } else if sticker.is_video {
+            StickerFormat::Video
+        } else {
+            StickerFormat::Static
+        }
+    }
+}
+
+/// Type of the stickers, it's the same for all stickers of a sticker set
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StickerKind {
+    Regular,
+    Mask,
+    CustomEmoji,
+}
+
+impl StickerKind {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            StickerKind::Regular => REGULAR_STICKER_TYPE,
+            StickerKind::Mask => MASK_STICKER_TYPE,
+            StickerKind::CustomEmoji => CUSTOM_EMOJI_STICKER_TYPE,
+        }
+    }
+
+    /// Human readable name of the stickers of this kind
+    pub const fn description(self) -> &'static str {
+        match self {
+            StickerKind::Regular => "regular stickers",
+            StickerKind::Mask => "masks",
+            StickerKind::CustomEmoji => "custom emoji",
+        }
+    }
+
+    /// Max width and height of the stickers in pixels
+    pub const fn max_side(self) -> i64 {
+        match self {
+            StickerKind::Regular | StickerKind::Mask => 512,
+            StickerKind::CustomEmoji => 100,
+        }
+    }
+
+    /// Returns `true` if the stickers of `kind` can be added into a sticker set of this kind.
+    /// Masks are regular stickers with a position on the face, so they can be mixed.
+    pub const fn accepts(self, kind: StickerKind) -> bool {
+        match (self, kind) {
+            (StickerKind::CustomEmoji, StickerKind::CustomEmoji) => true,
+            (StickerKind::CustomEmoji, _) | (_, StickerKind::CustomEmoji) => false,
+            _ => true,
+        }
+    }
+
+    /// Check that the `sticker` can be added into a sticker set of this kind and return its format
+    pub fn check(self, sticker: &Sticker) -> Result<StickerFormat, IncompatibleSticker> {
+        let kind = StickerKind::from(sticker);
+        if !self.accepts(kind) {
+            return Err(IncompatibleSticker::Kind {
+                sticker: kind,
+                set: self,
+            });
+        }
+
+        let format = StickerFormat::from(sticker);
+        if let Some(size) = sticker.file_size
+            && size > format.max_file_size()
+        {
+            return Err(IncompatibleSticker::FileTooLarge { format, size });
+        }
+
+        if sticker.width > self.max_side() || sticker.height > self.max_side() {
+            return Err(IncompatibleSticker::TooBig {
+                width: sticker.width,
+                height: sticker.height,
+                max_side: self.max_side(),
+            });
+        }
+
+        Ok(format)
+    }
+}
+
+impl From<&str> for StickerKind {
+    /// Telegram has only these three types, unknown ones are treated as regular stickers
+    fn from(sticker_type: &str) -> Self {
+        match sticker_type {
+            MASK_STICKER_TYPE => StickerKind::Mask,
+            CUSTOM_EMOJI_STICKER_TYPE => StickerKind::CustomEmoji,
+            _ => StickerKind::Regular,
+        }
+    }
+}
+
+impl From<&Sticker> for StickerKind {
+    fn from(sticker: &Sticker) -> Self {
+        StickerKind::from(&*sticker.sticker_type)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum IncompatibleSticker {
+    #[error("{} can't be added into a sticker pack of {}", .sticker.description(), .set.description())]
+    Kind {
+        sticker: StickerKind,
+        set: StickerKind,
+    },
+    #[error("{} sticker files can be up to {} KB, but this one is {} KB", .format.as_str(), .format.max_file_size() / 1024, .size / 1024)]
+    FileTooLarge { format: StickerFormat, size: i64 },
+    #[error(
+        "stickers of this pack can be up to {max_side}x{max_side} pixels, but this one is {width}x{height}"
+    )]
+    TooBig {
+        width: i64,
+        height: i64,
+        max_side: i64,
+    },
+}
+
+#[test]
+fn sticker_kind_test() {
+    let stickers: Vec<Sticker> = serde_json::from_str(
+        r#"[
+            {
+                "file_id": "static_file_id",
+                "file_unique_id": "static",
+                "type": "regular",
+                "width": 512,
+                "height": 512,
+                "is_animated": false,
+                "is_video": false,
+                "file_size": 30000
+            },
+            {
+                "file_id": "video_file_id",
+                "file_unique_id": "video",
+                "type": "mask",
+                "width": 512,
+                "height": 480,
+                "is_animated": false,
+                "is_video": true
+            },
+            {
+                "file_id": "emoji_file_id",
+                "file_unique_id": "emoji",
+                "type": "custom_emoji",
+                "width": 100,
+                "height": 100,
+                "is_animated": true,
+                "is_video": false
+            },
+            {
+                "file_id": "large_file_id",
+                "file_unique_id": "large",
+                "type": "regular",
+                "width": 512,
+                "height": 512,
+                "is_animated": true,
+                "is_video": false,
+                "file_size": 100000
+            }
+        ]"#,
+    )
+    .unwrap();
+
+    assert_eq!(StickerFormat::from(&stickers[0]), StickerFormat::Static);
+    assert_eq!(StickerFormat::from(&stickers[1]), StickerFormat::Video);
+    assert_eq!(StickerFormat::from(&stickers[2]), StickerFormat::Animated);
+
+    assert_eq!(StickerKind::from(&stickers[1]), StickerKind::Mask);
+    assert_eq!(StickerKind::from("unknown"), StickerKind::Regular);
+
+    assert_eq!(
+        StickerKind::Regular.check(&stickers[0]),
+        Ok(StickerFormat::Static)
+    );
+    assert_eq!(
+        StickerKind::Regular.check(&stickers[1]),
+        Ok(StickerFormat::Video)
+    );
+    assert_eq!(
+        StickerKind::Regular.check(&stickers[2]),
+        Err(IncompatibleSticker::Kind {
+            sticker: StickerKind::CustomEmoji,
+            set: StickerKind::Regular
+        })
+    );
+    assert_eq!(
+        StickerKind::CustomEmoji.check(&stickers[2]),
+        Ok(StickerFormat::Animated)
+    );
+    assert_eq!(
+        StickerKind::CustomEmoji.check(&stickers[0]),
+        Err(IncompatibleSticker::Kind {
+            sticker: StickerKind::Regular,
+            set: StickerKind::CustomEmoji
+        })
+    );
+    assert_eq!(
+        StickerKind::Regular.check(&stickers[3]),
+        Err(IncompatibleSticker::FileTooLarge {
+            format: StickerFormat::Animated,
+            size: 100000
+        })
+    );
+}
@@ -0,0 +1,16 @@
+BEGIN;
+
+CREATE TABLE IF NOT EXISTS steal_jobs (
+    tg_id BIGINT NOT NULL,
+    source_short_name TEXT NOT NULL,
+    short_name TEXT NOT NULL,
+    title TEXT NOT NULL,
+    stickers JSONB NOT NULL,
+    processed INTEGER NOT NULL DEFAULT 0,
+    failed INTEGER NOT NULL DEFAULT 0,
+    finished BOOLEAN NOT NULL DEFAULT FALSE,
+    created TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(short_name)
+);
+
+COMMIT;
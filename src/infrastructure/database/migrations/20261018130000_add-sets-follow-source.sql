@@ -0,0 +1,6 @@
+BEGIN;
+
+ALTER TABLE sets ADD COLUMN IF NOT EXISTS source_short_name TEXT;
+ALTER TABLE sets ADD COLUMN IF NOT EXISTS follow_source BOOLEAN NOT NULL DEFAULT FALSE;
+
+COMMIT;
@@ -0,0 +1,10 @@
+BEGIN;
+
+-- `source_short_name` column was added together with `follow_source`
+ALTER TABLE sets ADD COLUMN IF NOT EXISTS source_title TEXT;
+-- sticker sets stolen before keep NULL, because we don't know when they were stolen
+ALTER TABLE sets ADD COLUMN IF NOT EXISTS created_at TIMESTAMPTZ;
+ALTER TABLE sets ALTER COLUMN created_at SET DEFAULT CURRENT_TIMESTAMP;
+ALTER TABLE sets ADD COLUMN IF NOT EXISTS sticker_count INTEGER;
+
+COMMIT;
@@ -0,0 +1,14 @@
+BEGIN;
+
+-- the job is saved before its sticker set is created, and the short name can change while creating it,
+-- so the jobs are identified by id
+ALTER TABLE steal_jobs ADD COLUMN IF NOT EXISTS id BIGSERIAL;
+ALTER TABLE steal_jobs DROP CONSTRAINT IF EXISTS steal_jobs_short_name_key;
+CREATE UNIQUE INDEX IF NOT EXISTS steal_jobs_id_idx ON steal_jobs (id);
+
+-- everything that is needed to create the sticker set, if the bot was restarted before it
+ALTER TABLE steal_jobs ADD COLUMN IF NOT EXISTS sticker_type TEXT NOT NULL DEFAULT 'regular';
+ALTER TABLE steal_jobs ADD COLUMN IF NOT EXISTS source_title TEXT;
+ALTER TABLE steal_jobs ADD COLUMN IF NOT EXISTS thumbnail JSONB;
+
+COMMIT;
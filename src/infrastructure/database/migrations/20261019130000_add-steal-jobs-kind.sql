@@ -0,0 +1,7 @@
+BEGIN;
+
+-- jobs that add stickers into the existing sticker sets are saved too, and they have no source
+ALTER TABLE steal_jobs ADD COLUMN IF NOT EXISTS kind TEXT NOT NULL DEFAULT 'steal';
+ALTER TABLE steal_jobs ALTER COLUMN source_short_name DROP NOT NULL;
+
+COMMIT;
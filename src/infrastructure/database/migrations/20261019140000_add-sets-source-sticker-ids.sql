@@ -0,0 +1,7 @@
+BEGIN;
+
+-- `file_unique_id`s of the stickers, that the source sticker set had when the sticker set started to follow it
+-- or was updated last time. Only stickers that appeared in the source after it are added into the sticker set.
+ALTER TABLE sets ADD COLUMN IF NOT EXISTS source_sticker_ids JSONB;
+
+COMMIT;
@@ -1,2 +1,3 @@
 pub mod set;
+pub mod steal_job;
 pub mod user;
@@ -1,5 +1,6 @@
 use crate::domain::entities::set::Set as SetEntitie;
-use sqlx::FromRow;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, types::Json};
 
 #[derive(Debug, Clone, PartialEq, Eq, FromRow)]
 pub struct Set {
@@ -7,6 +8,12 @@ pub struct Set {
     pub short_name: String,
     pub deleted: bool,
     pub title: String,
+    pub source_short_name: Option<String>,
+    pub follow_source: bool,
+    pub source_title: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub sticker_count: Option<i32>,
+    pub source_sticker_ids: Option<Json<Vec<String>>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, FromRow)]
@@ -25,6 +32,12 @@ impl From<Set> for SetEntitie {
             short_name: value.short_name,
             deleted: value.deleted,
             title: value.title,
+            source_short_name: value.source_short_name,
+            follow_source: value.follow_source,
+            source_title: value.source_title,
+            created_at: value.created_at,
+            sticker_count: value.sticker_count,
+            source_sticker_ids: value.source_sticker_ids.map(|ids| ids.0),
         }
     }
 }
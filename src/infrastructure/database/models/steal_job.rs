@@ -0,0 +1,40 @@
+use crate::domain::entities::steal_job::StealJob as StealJobEntitie;
+use sqlx::{FromRow, types::Json};
+use telers::types::{PhotoSize, Sticker};
+
+#[derive(Debug, Clone, PartialEq, FromRow)]
+pub struct StealJob {
+    pub id: i64,
+    pub kind: String,
+    pub tg_id: i64,
+    pub source_short_name: Option<String>,
+    pub source_title: Option<String>,
+    pub short_name: String,
+    pub title: String,
+    pub sticker_type: String,
+    pub thumbnail: Option<Json<PhotoSize>>,
+    pub stickers: Json<Vec<Sticker>>,
+    pub processed: i32,
+    pub failed: i32,
+    pub finished: bool,
+}
+
+impl From<StealJob> for StealJobEntitie {
+    fn from(value: StealJob) -> Self {
+        Self {
+            id: value.id,
+            kind: value.kind.as_str().into(),
+            tg_id: value.tg_id,
+            source_short_name: value.source_short_name,
+            source_title: value.source_title,
+            short_name: value.short_name,
+            title: value.title,
+            sticker_type: value.sticker_type,
+            thumbnail: value.thumbnail.map(|thumbnail| thumbnail.0),
+            stickers: value.stickers.0,
+            processed: value.processed,
+            failed: value.failed,
+            finished: value.finished,
+        }
+    }
+}
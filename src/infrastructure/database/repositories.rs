@@ -1,6 +1,7 @@
 use crate::application::common::exceptions::{ApplicationException, RepoError, RepoKind};
 
 pub mod set;
+pub mod steal_job;
 pub mod user;
 
 impl From<sqlx::Error> for RepoError {
@@ -11,8 +11,11 @@ use crate::{
             dto::{
                 count_by_tg_id::CountByTgID, create::Create,
                 delete_by_short_name::DeleteByShortName, get_all::GetAll,
-                get_by_short_name::GetByShortName, get_by_tg_id::GetByTgID,
-                set_deleted_col_by_short_name::SetDeletedColByShortName,
+                get_by_short_name::GetByShortName, get_by_source_short_name::GetBySourceShortName,
+                get_by_tg_id::GetByTgID, set_deleted_col_by_short_name::SetDeletedColByShortName,
+                set_follow_source_col_by_short_name::SetFollowSourceColByShortName,
+                update_source_sticker_ids::UpdateSourceStickerIds,
+                update_sticker_count::UpdateStickerCount, update_title::UpdateTitle,
             },
             exceptions::{SetShortNameAlreadyExist, SetShortNameNotExist, SetTgIdNotExist},
             repository::SetRepo,
@@ -44,11 +47,17 @@ impl SetRepo for SetRepoImpl<&mut PgConnection> {
                 Alias::new("tg_id"),
                 Alias::new("short_name"),
                 Alias::new("title"),
+                Alias::new("source_short_name"),
+                Alias::new("source_title"),
+                Alias::new("sticker_count"),
             ])
             .values_panic([
                 set.tg_id().into(),
                 set.short_name().into(),
                 set.title().into(),
+                set.source_short_name().into(),
+                set.source_title().into(),
+                set.sticker_count().into(),
             ])
             .build_sqlx(PostgresQueryBuilder);
 
@@ -114,6 +123,12 @@ impl SetRepo for SetRepoImpl<&mut PgConnection> {
                     Alias::new("short_name"),
                     Alias::new("title"),
                     Alias::new("deleted"),
+                    Alias::new("source_short_name"),
+                    Alias::new("follow_source"),
+                    Alias::new("source_title"),
+                    Alias::new("created_at"),
+                    Alias::new("sticker_count"),
+                    Alias::new("source_sticker_ids"),
                 ])
                 .from(Alias::new("sets"))
                 .and_where(Expr::col(Alias::new("tg_id")).eq(set.tg_id()))
@@ -129,6 +144,12 @@ impl SetRepo for SetRepoImpl<&mut PgConnection> {
                     Alias::new("short_name"),
                     Alias::new("title"),
                     Alias::new("deleted"),
+                    Alias::new("source_short_name"),
+                    Alias::new("follow_source"),
+                    Alias::new("source_title"),
+                    Alias::new("created_at"),
+                    Alias::new("sticker_count"),
+                    Alias::new("source_sticker_ids"),
                 ])
                 .from(Alias::new("sets"))
                 .and_where(Expr::col(Alias::new("tg_id")).eq(set.tg_id()))
@@ -159,6 +180,13 @@ impl SetRepo for SetRepoImpl<&mut PgConnection> {
                 Alias::new("tg_id"),
                 Alias::new("short_name"),
                 Alias::new("title"),
+                Alias::new("deleted"),
+                Alias::new("source_short_name"),
+                Alias::new("follow_source"),
+                Alias::new("source_title"),
+                Alias::new("created_at"),
+                Alias::new("sticker_count"),
+                Alias::new("source_sticker_ids"),
             ])
             .from(Alias::new("sets"))
             .and_where(Expr::col(Alias::new("short_name")).eq(set.short_name()))
@@ -214,6 +242,195 @@ impl SetRepo for SetRepoImpl<&mut PgConnection> {
             })
     }
 
+    async fn set_follow_source_col_by_short_name<'a>(
+        &'a mut self,
+        set: SetFollowSourceColByShortName<'a>,
+    ) -> Result<(), RepoKind<SetShortNameNotExist>> {
+        let (sql_query, values) = Query::update()
+            .table(Alias::new("sets"))
+            .value(Alias::new("follow_source"), set.follow_source())
+            .and_where(Expr::col(Alias::new("short_name")).eq(set.short_name()))
+            .build_sqlx(PostgresQueryBuilder);
+
+        debug!(
+            "Postgres `set_follow_source_col_by_short_name` query: `{sql_query}`;\nValues for query: `{values:?}`"
+        );
+
+        sqlx::query_with(&sql_query, values)
+            .execute(&mut *self.conn)
+            .await
+            .map(|_| ())
+            .map_err(|err| {
+                if let sqlx::Error::RowNotFound = err {
+                    return RepoKind::exception(SetShortNameNotExist::new(
+                        set.short_name().to_string(),
+                        err.to_string(),
+                    ));
+                }
+
+                RepoKind::unexpected(err)
+            })
+    }
+
+    async fn update_title<'a>(
+        &'a mut self,
+        set: UpdateTitle<'a>,
+    ) -> Result<(), RepoKind<SetShortNameNotExist>> {
+        let (sql_query, values) = Query::update()
+            .table(Alias::new("sets"))
+            .value(Alias::new("title"), set.title())
+            .and_where(Expr::col(Alias::new("short_name")).eq(set.short_name()))
+            .build_sqlx(PostgresQueryBuilder);
+
+        debug!("Postgres `update_title` query: `{sql_query}`;\nValues for query: `{values:?}`");
+
+        sqlx::query_with(&sql_query, values)
+            .execute(&mut *self.conn)
+            .await
+            .map(|_| ())
+            .map_err(|err| {
+                if let sqlx::Error::RowNotFound = err {
+                    return RepoKind::exception(SetShortNameNotExist::new(
+                        set.short_name().to_string(),
+                        err.to_string(),
+                    ));
+                }
+
+                RepoKind::unexpected(err)
+            })
+    }
+
+    async fn update_source_sticker_ids<'a>(
+        &'a mut self,
+        set: UpdateSourceStickerIds<'a>,
+    ) -> Result<(), RepoKind<SetShortNameNotExist>> {
+        let source_sticker_ids = serde_json::to_value(set.source_sticker_ids())
+            .map_err(|err| RepoKind::unexpected(RepoError::new(err.to_string())))?;
+
+        let (sql_query, values) = Query::update()
+            .table(Alias::new("sets"))
+            .value(Alias::new("source_sticker_ids"), source_sticker_ids)
+            .and_where(Expr::col(Alias::new("short_name")).eq(set.short_name()))
+            .build_sqlx(PostgresQueryBuilder);
+
+        debug!(
+            "Postgres `update_source_sticker_ids` query: `{sql_query}`;\nValues for query: `{values:?}`"
+        );
+
+        sqlx::query_with(&sql_query, values)
+            .execute(&mut *self.conn)
+            .await
+            .map(|_| ())
+            .map_err(|err| {
+                if let sqlx::Error::RowNotFound = err {
+                    return RepoKind::exception(SetShortNameNotExist::new(
+                        set.short_name().to_string(),
+                        err.to_string(),
+                    ));
+                }
+
+                RepoKind::unexpected(err)
+            })
+    }
+
+    async fn update_sticker_count<'a>(
+        &'a mut self,
+        set: UpdateStickerCount<'a>,
+    ) -> Result<(), RepoKind<SetShortNameNotExist>> {
+        // the count stays unknown (`NULL`) for the sticker sets stolen before it was saved
+        let (sql_query, values) = Query::update()
+            .table(Alias::new("sets"))
+            .value(
+                Alias::new("sticker_count"),
+                Expr::col(Alias::new("sticker_count")).add(set.delta()),
+            )
+            .and_where(Expr::col(Alias::new("short_name")).eq(set.short_name()))
+            .build_sqlx(PostgresQueryBuilder);
+
+        debug!(
+            "Postgres `update_sticker_count` query: `{sql_query}`;\nValues for query: `{values:?}`"
+        );
+
+        sqlx::query_with(&sql_query, values)
+            .execute(&mut *self.conn)
+            .await
+            .map(|_| ())
+            .map_err(|err| {
+                if let sqlx::Error::RowNotFound = err {
+                    return RepoKind::exception(SetShortNameNotExist::new(
+                        set.short_name().to_string(),
+                        err.to_string(),
+                    ));
+                }
+
+                RepoKind::unexpected(err)
+            })
+    }
+
+    async fn get_by_source_short_name<'a>(
+        &'a mut self,
+        set: GetBySourceShortName<'a>,
+    ) -> Result<Vec<Set>, RepoError> {
+        let (sql_query, values) = Query::select()
+            .columns([
+                Alias::new("tg_id"),
+                Alias::new("short_name"),
+                Alias::new("title"),
+                Alias::new("deleted"),
+                Alias::new("source_short_name"),
+                Alias::new("follow_source"),
+                Alias::new("source_title"),
+                Alias::new("created_at"),
+                Alias::new("sticker_count"),
+                Alias::new("source_sticker_ids"),
+            ])
+            .from(Alias::new("sets"))
+            .and_where(Expr::col(Alias::new("tg_id")).eq(set.tg_id()))
+            .and_where(Expr::col(Alias::new("source_short_name")).eq(set.source_short_name()))
+            .and_where(Expr::col(Alias::new("deleted")).eq(false))
+            .build_sqlx(PostgresQueryBuilder);
+
+        debug!(
+            "Postgres `get_by_source_short_name` query: `{sql_query}`;\nValues for query: `{values:?}`"
+        );
+
+        sqlx::query_as_with(&sql_query, values)
+            .fetch_all(&mut *self.conn)
+            .await
+            .map(|set_model: Vec<SetModel>| set_model.into_iter().map(Into::into).collect())
+            .map_err(|err| RepoError::new(err.to_string()))
+    }
+
+    async fn get_following_source(&mut self) -> Result<Vec<Set>, RepoError> {
+        let (sql_query, values) = Query::select()
+            .columns([
+                Alias::new("tg_id"),
+                Alias::new("short_name"),
+                Alias::new("title"),
+                Alias::new("deleted"),
+                Alias::new("source_short_name"),
+                Alias::new("follow_source"),
+                Alias::new("source_title"),
+                Alias::new("created_at"),
+                Alias::new("sticker_count"),
+                Alias::new("source_sticker_ids"),
+            ])
+            .from(Alias::new("sets"))
+            .and_where(Expr::col(Alias::new("follow_source")).eq(true))
+            .and_where(Expr::col(Alias::new("deleted")).eq(false))
+            .build_sqlx(PostgresQueryBuilder);
+
+        debug!(
+            "Postgres `get_following_source` query: `{sql_query}`;\nValues for query: `{values:?}`"
+        );
+
+        sqlx::query_as_with(&sql_query, values)
+            .fetch_all(&mut *self.conn)
+            .await
+            .map(|set_model: Vec<SetModel>| set_model.into_iter().map(Into::into).collect())
+            .map_err(|err| RepoError::new(err.to_string()))
+    }
+
     async fn get_all(&mut self, set: GetAll) -> Result<Vec<Set>, RepoError> {
         let (sql_query, values) = if set.get_deleted().is_some() {
             Query::select()
@@ -222,6 +439,12 @@ impl SetRepo for SetRepoImpl<&mut PgConnection> {
                     Alias::new("short_name"),
                     Alias::new("title"),
                     Alias::new("deleted"),
+                    Alias::new("source_short_name"),
+                    Alias::new("follow_source"),
+                    Alias::new("source_title"),
+                    Alias::new("created_at"),
+                    Alias::new("sticker_count"),
+                    Alias::new("source_sticker_ids"),
                 ])
                 .from(Alias::new("sets"))
                 .and_where(
@@ -236,6 +459,12 @@ impl SetRepo for SetRepoImpl<&mut PgConnection> {
                     Alias::new("short_name"),
                     Alias::new("title"),
                     Alias::new("deleted"),
+                    Alias::new("source_short_name"),
+                    Alias::new("follow_source"),
+                    Alias::new("source_title"),
+                    Alias::new("created_at"),
+                    Alias::new("sticker_count"),
+                    Alias::new("source_sticker_ids"),
                 ])
                 .from(Alias::new("sets"))
                 .build_sqlx(PostgresQueryBuilder)
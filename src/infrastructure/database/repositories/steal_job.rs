@@ -0,0 +1,170 @@
+use async_trait::async_trait;
+use sea_query::{Alias, Expr, Order, PostgresQueryBuilder, Query};
+use sea_query_binder::SqlxBinder as _;
+use sqlx::PgConnection;
+use tracing::debug;
+
+use crate::{
+    application::{
+        common::exceptions::{RepoError, RepoKind},
+        steal_job::{
+            dto::{
+                create::Create, get_all::GetAll, update_progress::UpdateProgress,
+                update_short_name::UpdateShortName,
+            },
+            exceptions::StealJobNotExist,
+            repository::StealJobRepo,
+        },
+    },
+    domain::entities::steal_job::StealJob,
+    infrastructure::database::models::steal_job::StealJob as StealJobModel,
+};
+
+pub struct StealJobRepoImpl<Conn> {
+    conn: Conn,
+}
+
+impl<Conn> StealJobRepoImpl<Conn> {
+    pub fn new(conn: Conn) -> Self {
+        Self { conn }
+    }
+}
+
+#[async_trait]
+impl StealJobRepo for StealJobRepoImpl<&mut PgConnection> {
+    async fn create<'a>(&'a mut self, job: Create<'a>) -> Result<i64, RepoError> {
+        let stickers =
+            serde_json::to_value(job.stickers()).map_err(|err| RepoError::new(err.to_string()))?;
+        let thumbnail = job
+            .thumbnail()
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|err| RepoError::new(err.to_string()))?;
+
+        let (sql_query, values) = Query::insert()
+            .into_table(Alias::new("steal_jobs"))
+            .columns([
+                Alias::new("kind"),
+                Alias::new("tg_id"),
+                Alias::new("source_short_name"),
+                Alias::new("source_title"),
+                Alias::new("short_name"),
+                Alias::new("title"),
+                Alias::new("sticker_type"),
+                Alias::new("thumbnail"),
+                Alias::new("stickers"),
+            ])
+            .values_panic([
+                job.kind().as_str().into(),
+                job.tg_id().into(),
+                job.source_short_name().into(),
+                job.source_title().into(),
+                job.short_name().into(),
+                job.title().into(),
+                job.sticker_type().into(),
+                thumbnail.into(),
+                stickers.into(),
+            ])
+            .returning_col(Alias::new("id"))
+            .build_sqlx(PostgresQueryBuilder);
+
+        debug!("Postgres `create` query: `{sql_query}`;\nValues for query: `{values:?}`");
+
+        sqlx::query_scalar_with(&sql_query, values)
+            .fetch_one(&mut *self.conn)
+            .await
+            .map_err(|err| RepoError::new(err.to_string()))
+    }
+
+    async fn update_progress(
+        &mut self,
+        job: UpdateProgress,
+    ) -> Result<(), RepoKind<StealJobNotExist>> {
+        let (sql_query, values) = Query::update()
+            .table(Alias::new("steal_jobs"))
+            .values([
+                (Alias::new("processed"), job.processed().into()),
+                (Alias::new("failed"), job.failed().into()),
+                (Alias::new("finished"), job.finished().into()),
+            ])
+            .and_where(Expr::col(Alias::new("id")).eq(job.id()))
+            .build_sqlx(PostgresQueryBuilder);
+
+        debug!("Postgres `update_progress` query: `{sql_query}`;\nValues for query: `{values:?}`");
+
+        sqlx::query_with(&sql_query, values)
+            .execute(&mut *self.conn)
+            .await
+            .map(|_| ())
+            .map_err(|err| {
+                if let sqlx::Error::RowNotFound = err {
+                    return RepoKind::exception(StealJobNotExist::new(job.id(), err.to_string()));
+                }
+
+                RepoKind::unexpected(err)
+            })
+    }
+
+    async fn update_short_name<'a>(
+        &'a mut self,
+        job: UpdateShortName<'a>,
+    ) -> Result<(), RepoKind<StealJobNotExist>> {
+        let (sql_query, values) = Query::update()
+            .table(Alias::new("steal_jobs"))
+            .values([(Alias::new("short_name"), job.short_name().into())])
+            .and_where(Expr::col(Alias::new("id")).eq(job.id()))
+            .build_sqlx(PostgresQueryBuilder);
+
+        debug!(
+            "Postgres `update_short_name` query: `{sql_query}`;\nValues for query: `{values:?}`"
+        );
+
+        sqlx::query_with(&sql_query, values)
+            .execute(&mut *self.conn)
+            .await
+            .map(|_| ())
+            .map_err(|err| {
+                if let sqlx::Error::RowNotFound = err {
+                    return RepoKind::exception(StealJobNotExist::new(job.id(), err.to_string()));
+                }
+
+                RepoKind::unexpected(err)
+            })
+    }
+
+    async fn get_all(&mut self, job: GetAll) -> Result<Vec<StealJob>, RepoError> {
+        let mut query = Query::select();
+        query
+            .columns([
+                Alias::new("id"),
+                Alias::new("kind"),
+                Alias::new("tg_id"),
+                Alias::new("source_short_name"),
+                Alias::new("source_title"),
+                Alias::new("short_name"),
+                Alias::new("title"),
+                Alias::new("sticker_type"),
+                Alias::new("thumbnail"),
+                Alias::new("stickers"),
+                Alias::new("processed"),
+                Alias::new("failed"),
+                Alias::new("finished"),
+            ])
+            .from(Alias::new("steal_jobs"))
+            .order_by(Alias::new("created"), Order::Asc);
+
+        if let Some(get_finished) = job.get_finished() {
+            query.and_where(Expr::col(Alias::new("finished")).eq(get_finished));
+        }
+
+        let (sql_query, values) = query.build_sqlx(PostgresQueryBuilder);
+
+        debug!("Postgres `get_all` query: `{sql_query}`;\nValues for query: `{values:?}`");
+
+        sqlx::query_as_with(&sql_query, values)
+            .fetch_all(&mut *self.conn)
+            .await
+            .map(|job_models: Vec<StealJobModel>| job_models.into_iter().map(Into::into).collect())
+            .map_err(|err| RepoError::new(err.to_string()))
+    }
+}
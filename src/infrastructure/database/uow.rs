@@ -2,13 +2,14 @@ use async_trait::async_trait;
 use sqlx::{Database, Pool, Transaction};
 use telers::FromContext;
 
-use super::repositories::{set::SetRepoImpl, user::UserRepoImpl};
+use super::repositories::{set::SetRepoImpl, steal_job::StealJobRepoImpl, user::UserRepoImpl};
 use crate::application::{
     common::{
         exceptions::{BeginError, CommitError, RollbackError},
         traits::uow::{UoW as UnitOfWork, UoWFactory as UoWFactoryTrait},
     },
     set::repository::SetRepo,
+    steal_job::repository::StealJobRepo,
     user::repository::UserRepo,
 };
 
@@ -66,6 +67,7 @@ where
     DB: Database,
     for<'a> UserRepoImpl<&'a mut DB::Connection>: UserRepo,
     for<'a> SetRepoImpl<&'a mut DB::Connection>: SetRepo,
+    for<'a> StealJobRepoImpl<&'a mut DB::Connection>: StealJobRepo,
 {
     type UoW = UoW<DB>;
 
@@ -80,10 +82,12 @@ where
     DB: Database,
     for<'a> UserRepoImpl<&'a mut DB::Connection>: UserRepo,
     for<'a> SetRepoImpl<&'a mut DB::Connection>: SetRepo,
+    for<'a> StealJobRepoImpl<&'a mut DB::Connection>: StealJobRepo,
 {
     type Connection<'a> = &'a mut DB::Connection;
     type UserRepo<'a> = UserRepoImpl<Self::Connection<'a>>;
     type SetRepo<'a> = SetRepoImpl<Self::Connection<'a>>;
+    type StealJobRepo<'a> = StealJobRepoImpl<Self::Connection<'a>>;
 
     async fn connect(&mut self) -> Result<Self::Connection<'_>, BeginError> {
         if self.transaction.is_none() {
@@ -125,6 +129,10 @@ where
         Ok(SetRepoImpl::new(self.connect().await?))
     }
 
+    async fn steal_job_repo(&mut self) -> Result<Self::StealJobRepo<'_>, BeginError> {
+        Ok(StealJobRepoImpl::new(self.connect().await?))
+    }
+
     async fn user_repo(&mut self) -> Result<Self::UserRepo<'_>, BeginError> {
         Ok(UserRepoImpl::new(self.connect().await?))
     }
@@ -15,7 +15,9 @@ mod presentation;
 use crate::{
     config::{get_config_toml, init_tracing_subscriber_from_config},
     presentation::{
+        commands::claim_sticker_sets,
         router::start_bot,
+        scheduler::Scheduler,
         telegram_application::{client_authorize, client_connect},
     },
 };
@@ -41,7 +43,7 @@ async fn main() {
     debug!("Client connected!");
 
     debug!("Trying to log in..");
-    run_or_auth(&client, &config.auth.phone_number, &config.auth.password).await;
+    let command = run_or_auth(&client, &config.auth.phone_number, &config.auth.password).await;
     debug!("Successfully logged in!");
 
     debug!("Connecting to the database with url `{pg_url}`..");
@@ -55,6 +57,12 @@ async fn main() {
     };
     debug!("Connected the database!");
 
+    if let Commands::Claim { short_names } = command {
+        claim_sticker_sets(pool, Scheduler::new(bot.clone()), &client, &short_names).await;
+
+        process::exit(0);
+    }
+
     start_bot(bot, pool, client).await;
 }
 
@@ -71,9 +79,15 @@ pub enum Commands {
     Auth,
     /// Run programm (exit if client not authorized)
     Run,
+    /// Add sticker packs created by the bot, but missing from the database, for their owners and exit
+    Claim {
+        /// Short names of the sticker packs
+        #[arg(required = true)]
+        short_names: Vec<String>,
+    },
 }
 
-async fn run_or_auth(client: &Client, ph_num: &str, pswd: &str) {
+async fn run_or_auth(client: &Client, ph_num: &str, pswd: &str) -> Commands {
     let cli = Cli::parse();
 
     if Commands::Auth == cli.command {
@@ -88,9 +102,11 @@ async fn run_or_auth(client: &Client, ph_num: &str, pswd: &str) {
 
         process::exit(0);
     }
-    if Commands::Run == cli.command && !client.is_authorized().await.expect("error to authorize") {
+    if !client.is_authorized().await.expect("error to authorize") {
         error!("Client is not authorized! Run programm with command auth:\njust auth");
 
         process::exit(1);
     }
+
+    cli.command
 }
@@ -1,4 +1,8 @@
+pub mod chat_locks;
 pub mod commands;
+pub mod export;
+pub mod jobs;
 pub mod middlewares;
 pub mod router;
+pub mod scheduler;
 pub mod telegram_application;
@@ -0,0 +1,33 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use tokio::sync::{Mutex as AsyncMutex, OwnedMutexGuard};
+
+/// Locks of the chats to serialize reading and writing of the FSM data by the handlers,
+/// that run at the same time for the messages of the same chat, e.g. for the images of an album
+#[derive(Debug, Clone, Default)]
+pub struct ChatLocks {
+    locks: Arc<Mutex<HashMap<i64, Arc<AsyncMutex<()>>>>>,
+}
+
+impl ChatLocks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wait until the other handlers of the chat release its lock and take it.
+    /// The lock is released when the returned guard is dropped.
+    pub async fn lock(&self, chat_id: i64) -> OwnedMutexGuard<()> {
+        let lock = {
+            // only panic if other thread panicked while holding the lock, but we don't panic there
+            let mut locks = self.locks.lock().unwrap();
+            // the locks nobody holds or waits for aren't needed anymore
+            locks.retain(|_, lock| Arc::strong_count(lock) > 1);
+            locks.entry(chat_id).or_default().clone()
+        };
+
+        lock.lock_owned().await
+    }
+}
@@ -15,9 +15,11 @@ mod handlers;
 mod states;
 
 use crate::{
-    application::{set::repository::SetRepo, user::repository::UserRepo},
+    application::{
+        set::repository::SetRepo, steal_job::repository::StealJobRepo, user::repository::UserRepo,
+    },
     infrastructure::database::{
-        repositories::{set::SetRepoImpl, user::UserRepoImpl},
+        repositories::{set::SetRepoImpl, steal_job::StealJobRepoImpl, user::UserRepoImpl},
         uow::UoWFactory,
     },
     presentation::commands::{
@@ -29,21 +31,54 @@ use crate::{
     },
 };
 
-pub use handlers::deleted_sets_upd::deleted_sets_upd;
 use handlers::{
     add_stickers::{
-        add_stickers_handler, add_stickers_to_user_owned_sticker_set, get_stickers_to_add,
-        get_stolen_sticker_set,
+        add_entire_sticker_set_from_text, add_stickers_handler,
+        add_stickers_to_user_owned_sticker_set, get_images_to_add, get_stickers_to_add,
+        get_stolen_sticker_set, process_add_entire_sticker_set_button,
     },
     bot_src::source_handler,
     cancel::cancel_handler,
+    claim::{claim_handler, get_sticker_set_to_claim},
     common::{process_non_sticker_handler, process_non_text_handler},
+    delete_pack::{delete_pack_handler, process_delete_pack_buttons},
+    export::{export_handler, get_sticker_set_to_export, get_sticker_set_to_export_from_text},
+    follow::{follow_handler, toggle_follow_source},
+    merge::{create_merged_sticker_set, get_sticker_set_to_merge, merge_done, merge_handler},
     my_stickers::{my_stickers_handler, process_buttons as process_my_stickers_buttons},
+    new_pack::{create_new_pack, get_images_for_new_pack, new_pack_done, new_pack_handler},
+    remove_sticker::{
+        get_sticker_to_remove, remove_sticker_handler, remove_stickers_done, undo_remove_sticker,
+    },
+    rename::{get_sticker_set_to_rename, rename_handler, rename_sticker_set},
+    reorder::{
+        get_new_sticker_position, get_sticker_to_reorder, process_reorder_buttons, reorder_handler,
+    },
+    set_emoji::{
+        get_new_sticker_emoji, get_sticker_to_set_emoji, process_set_emoji_button,
+        set_emoji_handler,
+    },
     start::start_handler,
     stats::{process_buttons as process_stats_buttons, stats_handler},
-    steal_pack::{create_new_sticker_set, get_sticker_set_name, steal_sticker_set_handler},
+    steal_pack::{
+        get_new_sticker_set_title, get_short_name, get_sticker_set_name,
+        get_sticker_set_name_from_text, process_existing_copy_buttons,
+        process_select_stickers_buttons, select_stickers_from_text, skip_short_name,
+        steal_sticker_set_handler,
+    },
+};
+pub use handlers::{
+    claim::claim_sticker_sets, deleted_sets_upd::deleted_sets_upd,
+    followed_sets_upd::followed_sets_upd, steal_jobs::resume_steal_jobs,
+};
+use states::{
+    add_stickers::AddStickerState, claim_sticker_set::ClaimStickerSetState,
+    delete_sticker_set::DeleteStickerSetState, export_sticker_set::ExportStickerSetState,
+    follow_source::FollowSourceState, merge_sticker_sets::MergeStickerSetsState,
+    new_sticker_set::NewStickerSetState, remove_sticker::RemoveStickerState,
+    rename_sticker_set::RenameStickerSetState, reorder_stickers::ReorderStickersState,
+    set_emoji::SetEmojiState, steal_sticker_set::StealStickerSetState,
 };
-use states::{add_stickers::AddStickerState, steal_sticker_set::StealStickerSetState};
 
 pub async fn set_commands(bot: &Bot) -> Result<(), HandlerError> {
     let help_cmd = BotCommand::new("help", "Show help message");
@@ -54,6 +89,31 @@ pub async fn set_commands(bot: &Bot) -> Result<(), HandlerError> {
         "addstickers",
         "Add stickers to a sticker pack stolen by this bot",
     );
+    let new_pack_cmd = BotCommand::new("newpack", "Create a new sticker pack from images");
+    let merge_cmd = BotCommand::new("merge", "Merge several sticker packs into one");
+    let follow_cmd = BotCommand::new(
+        "follow",
+        "Add new stickers of the source pack into your stolen pack",
+    );
+    let remove_sticker_cmd = BotCommand::new(
+        "removesticker",
+        "Remove stickers from a sticker pack stolen by this bot",
+    );
+    let set_emoji_cmd = BotCommand::new(
+        "setemoji",
+        "Change emoji of stickers in a sticker pack stolen by this bot",
+    );
+    let rename_cmd = BotCommand::new("rename", "Rename a sticker pack stolen by this bot");
+    let reorder_cmd = BotCommand::new(
+        "reorder",
+        "Move stickers inside a sticker pack stolen by this bot",
+    );
+    let delete_pack_cmd = BotCommand::new("deletepack", "Delete a sticker pack stolen by this bot");
+    let claim_cmd = BotCommand::new(
+        "claim",
+        "Add a sticker pack made by this bot, but missing from your list",
+    );
+    let export_cmd = BotCommand::new("export", "Download a sticker pack as ZIP archive");
     let my_stickers_cmd = BotCommand::new("mystickers", "List of your stolen stickers");
     let stats_cmd = BotCommand::new("stats", "See the bot statistics");
     let cancel_cmd = BotCommand::new("cancel", "Cancel last command");
@@ -62,6 +122,16 @@ pub async fn set_commands(bot: &Bot) -> Result<(), HandlerError> {
     let private_chats = [
         steal_pack_cmd,
         add_stickers_cmd,
+        new_pack_cmd,
+        merge_cmd,
+        follow_cmd,
+        remove_sticker_cmd,
+        reorder_cmd,
+        set_emoji_cmd,
+        rename_cmd,
+        delete_pack_cmd,
+        claim_cmd,
+        export_cmd,
         my_stickers_cmd,
         stats_cmd,
         help_cmd,
@@ -81,6 +151,7 @@ where
     DB: Database,
     for<'a> UserRepoImpl<&'a mut DB::Connection>: UserRepo,
     for<'a> SetRepoImpl<&'a mut DB::Connection>: SetRepo,
+    for<'a> StealJobRepoImpl<&'a mut DB::Connection>: StealJobRepo,
 {
     process_non_command(
         router,
@@ -89,6 +160,16 @@ where
             "src",
             "stealpack",
             "addstickers",
+            "newpack",
+            "merge",
+            "follow",
+            "removesticker",
+            "reorder",
+            "setemoji",
+            "rename",
+            "deletepack",
+            "claim",
+            "export",
             "help",
             "cancel",
             "getowner",
@@ -100,9 +181,19 @@ where
     source_command(router, &["src", "source"]);
     cancel_command(router, "cancel");
     add_stickers_command::<DB>(router, "addstickers", "done", "undo");
-    steal_sticker_set_command::<DB>(router, "stealpack");
+    steal_sticker_set_command::<DB>(router, "stealpack", "skip");
+    new_pack_command::<DB>(router, "newpack", "done");
+    merge_command::<DB>(router, "merge", "done");
     stats_command::<DB>(router, "stats");
     my_stickers_command::<DB>(router, "mystickers");
+    follow_command::<DB>(router, "follow");
+    remove_sticker_command::<DB>(router, "removesticker", "done", "undo");
+    reorder_command(router, "reorder");
+    set_emoji_command(router, "setemoji");
+    rename_command::<DB>(router, "rename");
+    delete_pack_command::<DB>(router, "deletepack");
+    claim_command::<DB>(router, "claim");
+    export_command(router, "export");
     get_owner_command(router, "getowner");
     process_non_text(router);
     process_non_sticker(router);
@@ -113,6 +204,7 @@ where
     DB: Database,
     for<'a> UserRepoImpl<&'a mut DB::Connection>: UserRepo,
     for<'a> SetRepoImpl<&'a mut DB::Connection>: SetRepo,
+    for<'a> StealJobRepoImpl<&'a mut DB::Connection>: StealJobRepo,
 {
     router
         .message
@@ -168,6 +260,7 @@ fn add_stickers_command<DB>(
     DB: Database,
     for<'a> UserRepoImpl<&'a mut DB::Connection>: UserRepo,
     for<'a> SetRepoImpl<&'a mut DB::Connection>: SetRepo,
+    for<'a> StealJobRepoImpl<&'a mut DB::Connection>: StealJobRepo,
 {
     router
         .message
@@ -189,7 +282,16 @@ fn add_stickers_command<DB>(
 
     router
         .message
-        .register(add_stickers_to_user_owned_sticker_set::<MemoryStorage>)
+        .register(get_images_to_add::<MemoryStorage>)
+        .filter(ContentType::many([
+            ContentTypeEnum::Photo,
+            ContentTypeEnum::Document,
+        ]))
+        .filter(StateFilter::one(AddStickerState::GetStickersToAdd));
+
+    router
+        .message
+        .register(add_stickers_to_user_owned_sticker_set::<MemoryStorage, UoWFactory<DB>>)
         .filter(Command::one(done_command))
         .filter(StateFilter::one(AddStickerState::GetStickersToAdd));
 
@@ -198,32 +300,178 @@ fn add_stickers_command<DB>(
         .register(undo_last_sticker::<MemoryStorage>)
         .filter(Command::one(undo_command))
         .filter(StateFilter::one(AddStickerState::GetStickersToAdd));
+
+    router
+        .message
+        .register(add_entire_sticker_set_from_text::<MemoryStorage>)
+        .filter(ContentType::one(ContentTypeEnum::Text))
+        // don't process other commands as sticker set names
+        .filter(Text::starts_with_single("/").invert())
+        .filter(StateFilter::one(AddStickerState::GetStickersToAdd));
+
+    router
+        .callback_query
+        .register(process_add_entire_sticker_set_button::<MemoryStorage>)
+        .filter(Text::starts_with_single(
+            CallbackDataPrefix::AddEntirePack.as_str(),
+        ))
+        .filter(StateFilter::one(AddStickerState::GetStickersToAdd));
 }
 
 /// Executes Telegram command `/steal_pack`
-fn steal_sticker_set_command<DB>(router: &mut Router<Reqwest>, command: &'static str)
-where
+fn steal_sticker_set_command<DB>(
+    router: &mut Router<Reqwest>,
+    command: &'static str,
+    skip_command: &'static str,
+) where
     DB: Database,
     for<'a> UserRepoImpl<&'a mut DB::Connection>: UserRepo,
     for<'a> SetRepoImpl<&'a mut DB::Connection>: SetRepo,
+    for<'a> StealJobRepoImpl<&'a mut DB::Connection>: StealJobRepo,
 {
     router
         .message
-        .register(steal_sticker_set_handler::<MemoryStorage>)
+        .register(steal_sticker_set_handler::<MemoryStorage, UoWFactory<DB>>)
         .filter(Command::one(command))
         .filter(ContentType::one(ContentTypeEnum::Text));
 
     router
         .message
-        .register(get_sticker_set_name::<MemoryStorage>)
+        .register(get_sticker_set_name::<MemoryStorage, UoWFactory<DB>>)
         .filter(ContentType::one(ContentTypeEnum::Sticker))
         .filter(StateFilter::one(StealStickerSetState::StealStickerSetName));
 
     router
         .message
-        .register(create_new_sticker_set::<MemoryStorage, UoWFactory<DB>>)
+        .register(get_sticker_set_name_from_text::<MemoryStorage, UoWFactory<DB>>)
+        .filter(ContentType::one(ContentTypeEnum::Text))
+        // don't process other commands as sticker set names
+        .filter(Text::starts_with_single("/").invert())
+        .filter(StateFilter::one(StealStickerSetState::StealStickerSetName));
+
+    router
+        .callback_query
+        .register(process_existing_copy_buttons::<MemoryStorage, UoWFactory<DB>>)
+        .filter(Text::starts_with_single(
+            CallbackDataPrefix::ExistingCopy.as_str(),
+        ))
+        .filter(StateFilter::one(StealStickerSetState::ExistingCopy));
+
+    router
+        .callback_query
+        .register(process_select_stickers_buttons::<MemoryStorage>)
+        .filter(Text::starts_with_single(
+            CallbackDataPrefix::SelectStickers.as_str(),
+        ))
+        .filter(StateFilter::one(StealStickerSetState::SelectStickers));
+
+    router
+        .message
+        .register(select_stickers_from_text::<MemoryStorage>)
+        .filter(ContentType::one(ContentTypeEnum::Text))
+        .filter(Text::starts_with_single("/").invert())
+        .filter(StateFilter::one(StealStickerSetState::SelectStickers));
+
+    router
+        .message
+        .register(get_new_sticker_set_title::<MemoryStorage>)
         .filter(ContentType::one(ContentTypeEnum::Text))
         .filter(StateFilter::one(StealStickerSetState::CreateNewStickerSet));
+
+    router
+        .message
+        .register(skip_short_name::<MemoryStorage, UoWFactory<DB>>)
+        .filter(Command::one(skip_command))
+        .filter(StateFilter::one(StealStickerSetState::GetShortName));
+
+    router
+        .message
+        .register(get_short_name::<MemoryStorage, UoWFactory<DB>>)
+        .filter(ContentType::one(ContentTypeEnum::Text))
+        .filter(Text::starts_with_single("/").invert())
+        .filter(StateFilter::one(StealStickerSetState::GetShortName));
+}
+
+/// Executes Telegram command `/newpack`
+fn new_pack_command<DB>(
+    router: &mut Router<Reqwest>,
+    command: &'static str,
+    done_command: &'static str,
+) where
+    DB: Database,
+    for<'a> UserRepoImpl<&'a mut DB::Connection>: UserRepo,
+    for<'a> SetRepoImpl<&'a mut DB::Connection>: SetRepo,
+    for<'a> StealJobRepoImpl<&'a mut DB::Connection>: StealJobRepo,
+{
+    router
+        .message
+        .register(new_pack_handler::<MemoryStorage>)
+        .filter(Command::one(command))
+        .filter(ContentType::one(ContentTypeEnum::Text));
+
+    router
+        .message
+        .register(get_images_for_new_pack::<MemoryStorage>)
+        .filter(ContentType::many([
+            ContentTypeEnum::Photo,
+            ContentTypeEnum::Document,
+        ]))
+        .filter(StateFilter::one(NewStickerSetState::GetImages));
+
+    router
+        .message
+        .register(new_pack_done::<MemoryStorage>)
+        .filter(Command::one(done_command))
+        .filter(StateFilter::one(NewStickerSetState::GetImages));
+
+    router
+        .message
+        .register(create_new_pack::<MemoryStorage, UoWFactory<DB>>)
+        .filter(ContentType::one(ContentTypeEnum::Text))
+        .filter(Text::starts_with_single("/").invert())
+        .filter(StateFilter::one(NewStickerSetState::GetTitle));
+}
+
+/// Executes Telegram command `/merge`
+fn merge_command<DB>(
+    router: &mut Router<Reqwest>,
+    command: &'static str,
+    done_command: &'static str,
+) where
+    DB: Database,
+    for<'a> UserRepoImpl<&'a mut DB::Connection>: UserRepo,
+    for<'a> SetRepoImpl<&'a mut DB::Connection>: SetRepo,
+    for<'a> StealJobRepoImpl<&'a mut DB::Connection>: StealJobRepo,
+{
+    router
+        .message
+        .register(merge_handler::<MemoryStorage>)
+        .filter(Command::one(command))
+        .filter(ContentType::one(ContentTypeEnum::Text));
+
+    router
+        .message
+        .register(get_sticker_set_to_merge::<MemoryStorage>)
+        .filter(ContentType::one(ContentTypeEnum::Sticker))
+        .filter(StateFilter::one(
+            MergeStickerSetsState::GetStickerSetsToMerge,
+        ));
+
+    router
+        .message
+        .register(merge_done::<MemoryStorage>)
+        .filter(Command::one(done_command))
+        .filter(StateFilter::one(
+            MergeStickerSetsState::GetStickerSetsToMerge,
+        ));
+
+    router
+        .message
+        .register(create_merged_sticker_set::<MemoryStorage, UoWFactory<DB>>)
+        .filter(ContentType::one(ContentTypeEnum::Text))
+        .filter(StateFilter::one(
+            MergeStickerSetsState::CreateMergedStickerSet,
+        ));
 }
 
 /// Show all user stolen sticker sets
@@ -232,6 +480,7 @@ where
     DB: Database,
     for<'a> UserRepoImpl<&'a mut DB::Connection>: UserRepo,
     for<'a> SetRepoImpl<&'a mut DB::Connection>: SetRepo,
+    for<'a> StealJobRepoImpl<&'a mut DB::Connection>: StealJobRepo,
 {
     router
         .message
@@ -247,6 +496,229 @@ where
         ));
 }
 
+/// Executes Telegram command `/follow`
+fn follow_command<DB>(router: &mut Router<Reqwest>, command: &'static str)
+where
+    DB: Database,
+    for<'a> UserRepoImpl<&'a mut DB::Connection>: UserRepo,
+    for<'a> SetRepoImpl<&'a mut DB::Connection>: SetRepo,
+    for<'a> StealJobRepoImpl<&'a mut DB::Connection>: StealJobRepo,
+{
+    router
+        .message
+        .register(follow_handler::<MemoryStorage>)
+        .filter(Command::one(command))
+        .filter(ContentType::one(ContentTypeEnum::Text));
+
+    router
+        .message
+        .register(toggle_follow_source::<MemoryStorage, UoWFactory<DB>>)
+        .filter(ContentType::one(ContentTypeEnum::Sticker))
+        .filter(StateFilter::one(FollowSourceState::GetStolenStickerSet));
+}
+
+/// Executes Telegram command `/removesticker`
+fn remove_sticker_command<DB>(
+    router: &mut Router<Reqwest>,
+    command: &'static str,
+    done_command: &'static str,
+    undo_command: &'static str,
+) where
+    DB: Database,
+    for<'a> UserRepoImpl<&'a mut DB::Connection>: UserRepo,
+    for<'a> SetRepoImpl<&'a mut DB::Connection>: SetRepo,
+    for<'a> StealJobRepoImpl<&'a mut DB::Connection>: StealJobRepo,
+{
+    router
+        .message
+        .register(remove_sticker_handler::<MemoryStorage, UoWFactory<DB>>)
+        .filter(Command::one(command))
+        .filter(ContentType::one(ContentTypeEnum::Text));
+
+    router
+        .message
+        .register(get_sticker_to_remove::<MemoryStorage, UoWFactory<DB>>)
+        .filter(ContentType::one(ContentTypeEnum::Sticker))
+        .filter(StateFilter::one(RemoveStickerState::GetStickersToRemove));
+
+    router
+        .message
+        .register(undo_remove_sticker::<MemoryStorage, UoWFactory<DB>>)
+        .filter(Command::one(undo_command))
+        .filter(StateFilter::one(RemoveStickerState::GetStickersToRemove));
+
+    router
+        .message
+        .register(remove_stickers_done::<MemoryStorage>)
+        .filter(Command::one(done_command))
+        .filter(StateFilter::one(RemoveStickerState::GetStickersToRemove));
+}
+
+/// Executes Telegram command `/reorder`
+fn reorder_command(router: &mut Router<Reqwest>, command: &'static str) {
+    router
+        .message
+        .register(reorder_handler::<MemoryStorage>)
+        .filter(Command::one(command))
+        .filter(ContentType::one(ContentTypeEnum::Text));
+
+    router
+        .message
+        .register(get_sticker_to_reorder::<MemoryStorage>)
+        .filter(ContentType::one(ContentTypeEnum::Sticker))
+        // user can send another sticker instead of the position
+        .filter(StateFilter::many([
+            ReorderStickersState::GetSticker,
+            ReorderStickersState::GetPosition,
+        ]));
+
+    router
+        .message
+        .register(get_new_sticker_position::<MemoryStorage>)
+        .filter(ContentType::one(ContentTypeEnum::Text))
+        .filter(Text::starts_with_single("/").invert())
+        .filter(StateFilter::one(ReorderStickersState::GetPosition));
+
+    router
+        .callback_query
+        .register(process_reorder_buttons::<MemoryStorage>)
+        .filter(Text::starts_with_single(
+            CallbackDataPrefix::Reorder.as_str(),
+        ))
+        .filter(StateFilter::one(ReorderStickersState::GetPosition));
+}
+
+/// Executes Telegram command `/setemoji`
+fn set_emoji_command(router: &mut Router<Reqwest>, command: &'static str) {
+    router
+        .message
+        .register(set_emoji_handler::<MemoryStorage>)
+        .filter(Command::one(command))
+        .filter(ContentType::one(ContentTypeEnum::Text));
+
+    router
+        .message
+        .register(get_sticker_to_set_emoji::<MemoryStorage>)
+        .filter(ContentType::one(ContentTypeEnum::Sticker))
+        // user can send another sticker instead of the emoji
+        .filter(StateFilter::many([
+            SetEmojiState::GetSticker,
+            SetEmojiState::GetEmoji,
+        ]));
+
+    router
+        .message
+        .register(get_new_sticker_emoji::<MemoryStorage>)
+        .filter(ContentType::one(ContentTypeEnum::Text))
+        .filter(Text::starts_with_single("/").invert())
+        .filter(StateFilter::one(SetEmojiState::GetEmoji));
+
+    router
+        .callback_query
+        .register(process_set_emoji_button::<MemoryStorage>)
+        .filter(Text::starts_with_single(
+            CallbackDataPrefix::SetEmoji.as_str(),
+        ))
+        .filter(StateFilter::one(SetEmojiState::GetEmoji));
+}
+
+/// Executes Telegram command `/rename`
+fn rename_command<DB>(router: &mut Router<Reqwest>, command: &'static str)
+where
+    DB: Database,
+    for<'a> UserRepoImpl<&'a mut DB::Connection>: UserRepo,
+    for<'a> SetRepoImpl<&'a mut DB::Connection>: SetRepo,
+    for<'a> StealJobRepoImpl<&'a mut DB::Connection>: StealJobRepo,
+{
+    router
+        .message
+        .register(rename_handler::<MemoryStorage>)
+        .filter(Command::one(command))
+        .filter(ContentType::one(ContentTypeEnum::Text));
+
+    router
+        .message
+        .register(get_sticker_set_to_rename::<MemoryStorage>)
+        .filter(ContentType::one(ContentTypeEnum::Sticker))
+        .filter(StateFilter::one(RenameStickerSetState::GetStickerSet));
+
+    router
+        .message
+        .register(rename_sticker_set::<MemoryStorage, UoWFactory<DB>>)
+        .filter(ContentType::one(ContentTypeEnum::Text))
+        .filter(Text::starts_with_single("/").invert())
+        .filter(StateFilter::one(RenameStickerSetState::GetNewTitle));
+}
+
+/// Executes Telegram command `/deletepack`
+fn delete_pack_command<DB>(router: &mut Router<Reqwest>, command: &'static str)
+where
+    DB: Database,
+    for<'a> UserRepoImpl<&'a mut DB::Connection>: UserRepo,
+    for<'a> SetRepoImpl<&'a mut DB::Connection>: SetRepo,
+    for<'a> StealJobRepoImpl<&'a mut DB::Connection>: StealJobRepo,
+{
+    router
+        .message
+        .register(delete_pack_handler::<MemoryStorage, UoWFactory<DB>>)
+        .filter(Command::one(command))
+        .filter(ContentType::one(ContentTypeEnum::Text));
+
+    router
+        .callback_query
+        .register(process_delete_pack_buttons::<MemoryStorage, UoWFactory<DB>>)
+        .filter(Text::starts_with_single(
+            CallbackDataPrefix::DeletePack.as_str(),
+        ))
+        .filter(StateFilter::many([
+            DeleteStickerSetState::SelectStickerSet,
+            DeleteStickerSetState::Confirm,
+        ]));
+}
+
+/// Executes Telegram command `/claim`
+fn claim_command<DB>(router: &mut Router<Reqwest>, command: &'static str)
+where
+    DB: Database,
+    for<'a> UserRepoImpl<&'a mut DB::Connection>: UserRepo,
+    for<'a> SetRepoImpl<&'a mut DB::Connection>: SetRepo,
+    for<'a> StealJobRepoImpl<&'a mut DB::Connection>: StealJobRepo,
+{
+    router
+        .message
+        .register(claim_handler::<MemoryStorage, UoWFactory<DB>>)
+        .filter(Command::one(command))
+        .filter(ContentType::one(ContentTypeEnum::Text));
+
+    router
+        .message
+        .register(get_sticker_set_to_claim::<UoWFactory<DB>>)
+        .filter(ContentType::one(ContentTypeEnum::Sticker))
+        .filter(StateFilter::one(ClaimStickerSetState::GetStickerSet));
+}
+
+/// Executes Telegram command `/export`
+fn export_command(router: &mut Router<Reqwest>, command: &'static str) {
+    router
+        .message
+        .register(export_handler::<MemoryStorage>)
+        .filter(Command::one(command))
+        .filter(ContentType::one(ContentTypeEnum::Text));
+
+    router
+        .message
+        .register(get_sticker_set_to_export::<MemoryStorage>)
+        .filter(ContentType::one(ContentTypeEnum::Sticker))
+        .filter(StateFilter::one(ExportStickerSetState::GetStickerSet));
+
+    router
+        .message
+        .register(get_sticker_set_to_export_from_text::<MemoryStorage>)
+        .filter(ContentType::one(ContentTypeEnum::Text))
+        .filter(Text::starts_with_single("/").invert())
+        .filter(StateFilter::one(ExportStickerSetState::GetStickerSet));
+}
+
 fn get_owner_command(router: &mut Router<Reqwest>, command: &'static str) {
     router
         .message
@@ -272,7 +744,17 @@ fn process_non_sticker(router: &mut Router<Reqwest>) {
                     AddStickerState::GetStolenStickerSet,
                     AddStickerState::GetStickersToAdd,
                 ]))
-                .or(StateFilter::one(GetOwnerState::GetStickers)),
+                .or(StateFilter::one(GetOwnerState::GetStickers))
+                .or(StateFilter::one(RemoveStickerState::GetStickersToRemove))
+                .or(StateFilter::one(ReorderStickersState::GetSticker))
+                .or(StateFilter::one(RenameStickerSetState::GetStickerSet))
+                .or(StateFilter::one(SetEmojiState::GetSticker))
+                .or(StateFilter::one(ClaimStickerSetState::GetStickerSet))
+                .or(StateFilter::one(FollowSourceState::GetStolenStickerSet))
+                .or(StateFilter::one(
+                    MergeStickerSetsState::GetStickerSetsToMerge,
+                ))
+                .or(StateFilter::one(ExportStickerSetState::GetStickerSet)),
         );
 }
 
@@ -281,5 +763,16 @@ fn process_non_text(router: &mut Router<Reqwest>) {
         .message
         .register(process_non_text_handler)
         .filter(ContentType::one(ContentTypeEnum::Text).invert())
-        .filter(StateFilter::one(StealStickerSetState::CreateNewStickerSet));
+        .filter(
+            StateFilter::many([
+                StealStickerSetState::SelectStickers,
+                StealStickerSetState::CreateNewStickerSet,
+                StealStickerSetState::GetShortName,
+            ])
+            .or(StateFilter::one(
+                MergeStickerSetsState::CreateMergedStickerSet,
+            ))
+            .or(StateFilter::one(RenameStickerSetState::GetNewTitle))
+            .or(StateFilter::one(NewStickerSetState::GetTitle)),
+        );
 }
@@ -1,71 +1,283 @@
-use std::{borrow::Cow, time::Duration};
-
+use grammers_client::Client;
 use telers::{
-    Bot,
+    enums::ParseMode,
+    errors::SessionErrorKind,
     event::{EventReturn, telegram::HandlerResult},
-    methods::{AddStickerToSet, SendMessage},
-    types::{InputFile, InputSticker, Sticker},
+    methods::{GetFile, GetMe, SendMessage, UploadStickerFile},
+    types::{InputFile, Message, MessageText, ReplyParameters, Sticker},
+    utils::text::html_code,
 };
-use tracing::error;
 
-use crate::core::helpers::{common::sticker_format, texts::default_error_message};
-
-#[derive(Debug, Clone, thiserror::Error)]
-#[error("Error occurred while adding stickers: {message}")]
-pub(crate) struct AddStickersError {
-    message: Cow<'static, str>,
-}
+use tracing::error;
 
-impl AddStickersError {
-    fn new(message: impl Into<Cow<'static, str>>) -> Self {
-        Self {
-            message: message.into(),
-        }
-    }
-}
+use crate::{
+    core::helpers::{
+        common::{parse_emoji_list, set_created_by},
+        constants::{
+            DEFAULT_STICKER_EMOJI, MAX_DOWNLOAD_FILE_SIZE, MAX_SET_TITLE_LENGTH,
+            MIN_SET_TITLE_LENGTH, REGULAR_STICKER_TYPE,
+        },
+        sticker_image::{image_to_sticker, is_sticker_image_mime_type},
+        texts::default_error_message,
+    },
+    domain::entities::sticker::StickerFormat,
+    presentation::{scheduler::Scheduler, telegram_application::get_sticker_set_user_id},
+};
 
-pub async fn send_default_error_message(bot: &Bot, chat_id: i64) -> HandlerResult {
+pub async fn send_default_error_message(bot: &Scheduler, chat_id: i64) -> HandlerResult {
     bot.send(SendMessage::new(chat_id, default_error_message()))
         .await?;
 
     return Ok(EventReturn::Finish);
 }
 
-/// Returns `true` if all stickers was stolen, `false` otherwise
-pub async fn add_stickers(
-    bot: &Bot,
+/// Returns id and sticker of the message that `message` replies to, if it's a sticker.
+/// Commands can be sent as a reply to a sticker to skip the "send me a sticker" step.
+pub fn replied_sticker(message: &MessageText) -> Option<(i64, &Sticker)> {
+    match message.reply_to_message.as_deref() {
+        Some(Message::Sticker(reply)) => Some((reply.id, &reply.sticker)),
+        _ => None,
+    }
+}
+
+/// Returns `true` if sticker set `set_name` was stolen by the bot for the user `user_id`,
+/// otherwise tells the user what's wrong with it and returns `false`
+pub async fn check_set_owner(
+    bot: &Scheduler,
+    client: &Client,
+    chat_id: i64,
     user_id: i64,
     set_name: &str,
-    stickers: Vec<Sticker>,
-) -> Result<bool, AddStickersError> {
-    if stickers.is_empty() {
-        return Err(AddStickersError::new("list is empty"));
+) -> Result<bool, SessionErrorKind> {
+    // cant panic because bot cant be without username
+    let bot_username = bot
+        .send(GetMe::new())
+        .await?
+        .username
+        .expect("bot without username :/");
+
+    if !set_created_by(set_name, &bot_username) {
+        bot.send(SendMessage::new(
+            chat_id,
+            "This sticker pack wasn't stolen by me, which means that I cannot change it according to Telegram rules. \
+            You can view all your stolen stickers using /mystickers.",
+        ))
+        .await?;
+
+        return Ok(false);
     }
 
-    let mut all_stickers_was_stolen = true;
-    for sticker in stickers {
-        if let Err(err) = bot
-            .send(AddStickerToSet::new(user_id, set_name, {
-                let sticker_is = InputSticker::new(
-                    InputFile::id(sticker.file_id.as_ref()),
-                    sticker_format(&sticker),
-                );
-
-                sticker_is.emoji_list(sticker.emoji)
-            }))
-            .await
-        {
+    let sticker_set_user_id = match get_sticker_set_user_id(set_name, client).await {
+        Ok(id) => id,
+        Err(error) => {
             error!(
-                ?err,
+                ?error,
                 ?set_name,
-                "Error occurred while adding stickers to sticker set: "
+                "Error occurred while getting sticker set user id: "
             );
-            all_stickers_was_stolen = false;
+
+            bot.send(SendMessage::new(chat_id, default_error_message()))
+                .await?;
+
+            return Ok(false);
         }
+    };
+
+    if user_id != sticker_set_user_id {
+        bot.send(SendMessage::new(
+            chat_id,
+            "You are not the owner of this sticker pack. \
+            Please send me a sticker from your sticker pack that stolen by me.",
+        ))
+        .await?;
 
-        // sleep because you can’t send telegram api requests more often than per second
-        tokio::time::sleep(Duration::from_millis(1500)).await;
+        return Ok(false);
     }
 
-    Ok(all_stickers_was_stolen)
+    Ok(true)
+}
+
+/// Returns `true` if `title` can be used as a sticker set title,
+/// otherwise tells the user what's wrong with it and returns `false`
+pub async fn check_set_title(
+    bot: &Scheduler,
+    chat_id: i64,
+    title: &str,
+) -> Result<bool, SessionErrorKind> {
+    if title.len() > MAX_SET_TITLE_LENGTH {
+        bot.send(
+            SendMessage::new(
+                chat_id,
+                format!(
+                    "Too long name! Please enter a name up to {max_len} characters long.",
+                    max_len = html_code(MAX_SET_TITLE_LENGTH.to_string())
+                ),
+            )
+            .parse_mode(ParseMode::HTML),
+        )
+        .await?;
+
+        return Ok(false);
+    } else if title.len() < MIN_SET_TITLE_LENGTH {
+        bot.send(
+            SendMessage::new(
+                chat_id,
+                format!(
+                    "Too short name! Please enter a name between {min_len} and {max_len} characters long.",
+                    min_len = html_code(MIN_SET_TITLE_LENGTH.to_string()),
+                    max_len = html_code(MAX_SET_TITLE_LENGTH.to_string())
+                ),
+            )
+            .parse_mode(ParseMode::HTML),
+        )
+        .await?;
+
+        return Ok(false);
+    }
+
+    Ok(true)
+}
+
+/// Turn the photo or the image document of the `message` into a static sticker and upload it
+/// for the user `user_id`, so it can be added into the user's sticker sets. The emoji of the sticker
+/// is taken from the caption. If the image can't be turned into a sticker,
+/// tells the user what's wrong with it and returns `None`.
+pub async fn upload_sticker_image(
+    bot: &Scheduler,
+    message: &Message,
+    user_id: i64,
+) -> Result<Option<Sticker>, SessionErrorKind> {
+    let chat_id = message.chat().id();
+    let reply_parameters = ReplyParameters::new(message.id()).chat_id(chat_id);
+
+    let (file_id, file_size, caption) = match message {
+        // the last photo size is the largest one
+        Message::Photo(message) => match message.photo.last() {
+            Some(photo) => (&photo.file_id, photo.file_size, message.caption.as_deref()),
+            None => return Ok(None),
+        },
+        Message::Document(message)
+            if message
+                .document
+                .mime_type
+                .as_deref()
+                .is_some_and(is_sticker_image_mime_type) =>
+        {
+            (
+                &message.document.file_id,
+                message.document.file_size,
+                message.caption.as_deref(),
+            )
+        }
+        _ => {
+            bot.send(
+                SendMessage::new(
+                    chat_id,
+                    "Sorry, but I can make stickers only from photos and PNG, WebP or JPEG images.",
+                )
+                .reply_parameters(reply_parameters),
+            )
+            .await?;
+
+            return Ok(None);
+        }
+    };
+
+    if file_size.is_some_and(|file_size| file_size > MAX_DOWNLOAD_FILE_SIZE) {
+        bot.send(
+            SendMessage::new(
+                chat_id,
+                "Sorry, but this image is too large. Send me an image up to 20 MB.",
+            )
+            .reply_parameters(reply_parameters),
+        )
+        .await?;
+
+        return Ok(None);
+    }
+
+    let file = bot.send(GetFile::new(file_id.as_ref())).await?;
+    let image = match file.file_path.as_deref() {
+        Some(file_path) => bot.download_file(file_path).await,
+        None => {
+            error!(?file_id, "Telegram didn't return path of the image file");
+
+            bot.send(SendMessage::new(chat_id, default_error_message()))
+                .await?;
+
+            return Ok(None);
+        }
+    };
+    let image = match image {
+        Ok(image) => image,
+        Err(error) => {
+            error!(?error, ?file_id, "Error occurred while downloading image: ");
+
+            bot.send(SendMessage::new(chat_id, default_error_message()))
+                .await?;
+
+            return Ok(None);
+        }
+    };
+
+    // resizing of a large image takes a while, so don't block other handlers
+    let sticker_image = match tokio::task::spawn_blocking(move || image_to_sticker(&image)).await {
+        Ok(Ok(sticker_image)) => sticker_image,
+        Ok(Err(err)) => {
+            bot.send(
+                SendMessage::new(
+                    chat_id,
+                    format!("Sorry, but I can't make a sticker from this image: {err}. Try send another image."),
+                )
+                .reply_parameters(reply_parameters),
+            )
+            .await?;
+
+            return Ok(None);
+        }
+        Err(error) => {
+            error!(?error, "Error occurred while converting image to sticker: ");
+
+            bot.send(SendMessage::new(chat_id, default_error_message()))
+                .await?;
+
+            return Ok(None);
+        }
+    };
+
+    let emoji = caption
+        .and_then(parse_emoji_list)
+        .and_then(|emoji_list| emoji_list.into_iter().next())
+        .unwrap_or_else(|| DEFAULT_STICKER_EMOJI.to_owned());
+
+    let file_size = sticker_image.bytes.len() as i64;
+    let file = match bot
+        .send(UploadStickerFile::new(
+            user_id,
+            InputFile::buffered(sticker_image.bytes),
+            StickerFormat::Static.as_str(),
+        ))
+        .await
+    {
+        Ok(file) => file,
+        Err(error) => {
+            error!(?error, "Error occurred while uploading sticker file: ");
+
+            bot.send(SendMessage::new(chat_id, default_error_message()))
+                .await?;
+
+            return Ok(None);
+        }
+    };
+
+    Ok(Some(Sticker {
+        file_id: file.file_id,
+        file_unique_id: file.file_unique_id,
+        sticker_type: REGULAR_STICKER_TYPE.into(),
+        width: sticker_image.width.into(),
+        height: sticker_image.height.into(),
+        emoji: Some(emoji.into()),
+        file_size: Some(file_size),
+        ..Default::default()
+    }))
 }
@@ -1,10 +1,22 @@
 pub mod add_stickers;
 pub mod bot_src;
 pub mod cancel;
+pub mod claim;
 pub mod common;
+pub mod delete_pack;
 pub mod deleted_sets_upd;
+pub mod export;
+pub mod follow;
+pub mod followed_sets_upd;
 pub mod get_owner;
+pub mod merge;
 pub mod my_stickers;
+pub mod new_pack;
+pub mod remove_sticker;
+pub mod rename;
+pub mod reorder;
+pub mod set_emoji;
 pub mod start;
 pub mod stats;
+pub mod steal_jobs;
 pub mod steal_pack;
@@ -2,13 +2,16 @@ use std::time::Duration;
 
 use grammers_client::Client;
 use telers::{
-    Bot, Extension,
+    Extension,
     enums::ParseMode,
     errors::{TelegramErrorKind, session::ErrorKind},
     event::{EventReturn, telegram::HandlerResult},
     fsm::{Context, Storage},
-    methods::{DeleteMessage, GetMe, GetStickerSet, SendMessage, SendSticker},
-    types::{InputFile, InputFileId, MessageSticker, MessageText, ReplyParameters, Sticker},
+    methods::{AnswerCallbackQuery, DeleteMessage, GetMe, GetStickerSet, SendMessage, SendSticker},
+    types::{
+        CallbackQuery, InlineKeyboardButton, InlineKeyboardMarkup, InputFile, InputFileId, Message,
+        MessageSticker, MessageText, ReplyParameters, Sticker,
+    },
     utils::text::{html_code, html_quote, html_text_link},
 };
 use tracing::error;
@@ -16,22 +19,31 @@ use tracing::error;
 use crate::{
     application::common::traits::uow::UoWFactory as UoWFactoryTrait,
     core::helpers::{
-        common::set_created_by,
-        constants::{MAX_STICKER_SET_LENGTH, TELEGRAM_STICKER_SET_URL},
+        common::{
+            max_sticker_set_length, missing_stickers, parse_sticker_set_name, set_created_by,
+        },
+        constants::{MAX_CALLBACK_DATA_LENGTH, TELEGRAM_STICKER_SET_URL},
     },
+    domain::entities::sticker::StickerKind,
     presentation::{
+        chat_locks::ChatLocks,
         commands::{
-            common::{add_stickers, send_default_error_message},
-            states::add_stickers::AddStickerState,
+            common::{replied_sticker, send_default_error_message, upload_sticker_image},
+            states::{add_stickers::AddStickerState, callback_data::CallbackDataPrefix},
         },
+        jobs::{AddStickers, JobQueue},
+        scheduler::Scheduler,
         telegram_application::get_sticker_set_user_id,
     },
 };
 
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
 pub async fn add_stickers_handler<S: Storage>(
-    bot: Bot,
+    Extension(bot): Extension<Scheduler>,
     message: MessageText,
     fsm: Context<S>,
+    Extension(client): Extension<Client>,
 ) -> HandlerResult {
     fsm.finish().await.map_err(Into::into)?;
 
@@ -39,6 +51,20 @@ pub async fn add_stickers_handler<S: Storage>(
         .await
         .map_err(Into::into)?;
 
+    if let Some((reply_id, sticker)) = replied_sticker(&message) {
+        return process_stolen_sticker_set(
+            &bot,
+            &fsm,
+            &client,
+            message.chat.id(),
+            reply_id,
+            // only panic if messages uses in channels, but i'm using private filter
+            message.from.expect("user not specified").id,
+            sticker.set_name.clone(),
+        )
+        .await;
+    }
+
     bot.send(
         SendMessage::new(
             message.chat.id(),
@@ -52,8 +78,10 @@ pub async fn add_stickers_handler<S: Storage>(
     Ok(EventReturn::Finish)
 }
 
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
 pub async fn get_stolen_sticker_set<S, UoWFactory>(
-    bot: Bot,
+    Extension(bot): Extension<Scheduler>,
     message: MessageSticker,
     fsm: Context<S>,
     Extension(client): Extension<Client>,
@@ -62,11 +90,35 @@ where
     UoWFactory: UoWFactoryTrait,
     S: Storage,
 {
-    let sticker_set_name = match message.sticker.set_name {
+    process_stolen_sticker_set(
+        &bot,
+        &fsm,
+        &client,
+        message.chat.id(),
+        message.id,
+        // only panic if messages uses in channels, but i'm using private filter
+        message.from.expect("user not specified").id,
+        message.sticker.set_name,
+    )
+    .await
+}
+
+/// Check that sticker set `sticker_set_name` was stolen by the bot for the user `user_id`
+/// and move on to the selection of stickers to add into it
+async fn process_stolen_sticker_set<S: Storage>(
+    bot: &Scheduler,
+    fsm: &Context<S>,
+    client: &Client,
+    chat_id: i64,
+    message_id: i64,
+    user_id: i64,
+    sticker_set_name: Option<Box<str>>,
+) -> HandlerResult {
+    let sticker_set_name = match sticker_set_name {
         Some(sticker_set_name) => sticker_set_name,
         None => {
             bot.send(SendMessage::new(
-                message.chat.id(),
+                chat_id,
                 "This sticker is without sticker pack. Try to send another sticker pack.",
             ))
             .await?;
@@ -81,7 +133,7 @@ where
         if matches!(error, ErrorKind::Telegram(TelegramErrorKind::BadRequest { message }) if **message == *"Bad Request: STICKERSET_INVALID")
         {
             bot.send(SendMessage::new(
-                message.chat.id(),
+                chat_id,
                 "This sticker is without sticker pack. Try to send another sticker pack.",
             ))
             .await?;
@@ -94,7 +146,7 @@ where
             "Error occurred while getting sticker set name to steal: "
         );
 
-        send_default_error_message(&bot, message.chat.id()).await?;
+        send_default_error_message(bot, chat_id).await?;
 
         return Ok(EventReturn::Finish);
     }
@@ -110,7 +162,7 @@ where
 
     if !set_created_by(sticker_set_name.as_ref(), bot_username.as_ref()) {
         bot.send(SendMessage::new(
-            message.chat.id(),
+            chat_id,
             "This sticker pack wasn't stolen by me, which means that I cannot add stickers to it according to Telegram rules. \
             You can view all your stolen stickers using /mystickers or steal this pack using /stealpack.",
         ))
@@ -119,7 +171,7 @@ where
         return Ok(EventReturn::Finish);
     }
 
-    let sticker_set_user_id = match get_sticker_set_user_id(&sticker_set_name, &client).await {
+    let sticker_set_user_id = match get_sticker_set_user_id(&sticker_set_name, client).await {
         Ok(id) => id,
         Err(error) => {
             error!(
@@ -128,19 +180,16 @@ where
                 "Error occurred while getting sticker set user id: "
             );
 
-            send_default_error_message(&bot, message.chat.id()).await?;
+            send_default_error_message(bot, chat_id).await?;
 
             return Ok(EventReturn::Finish);
         }
     };
 
-    // only panic if messages uses in channels, but i'm using private filter
-    let user_id = message.from.expect("user not specified").id;
-
     if user_id != sticker_set_user_id {
         bot.send(
             SendMessage::new(
-                message.chat.id(),
+                chat_id,
                 "You are not the owner of this sticker pack. \
                 Please send me your sticker pack that stolen by me, or steal this pack using /stealpack.",
             )
@@ -152,28 +201,29 @@ where
     }
 
     let set_length = sticker_set.stickers.len();
+    let max_set_length = max_sticker_set_length(&sticker_set.sticker_type);
 
-    let message_delete = if MAX_STICKER_SET_LENGTH - set_length > 0 {
+    let message_delete = if set_length < max_set_length {
         bot.send(
             SendMessage::new(
-                message.chat.id(),
+                chat_id,
                 format!("Current length of this sticker pack is {set_length_code}. You can add {remaining} more stickers.",
                 set_length_code = html_code(set_length.to_string()),
-                remaining = html_code((MAX_STICKER_SET_LENGTH - set_length).to_string())
+                remaining = html_code((max_set_length - set_length).to_string())
             )).parse_mode(ParseMode::HTML)
-            .reply_parameters(ReplyParameters::new(message.id).chat_id(message.chat.id())),
+            .reply_parameters(ReplyParameters::new(message_id).chat_id(chat_id)),
     )
         .await?
     } else {
         bot.send(
             SendMessage::new(
-                message.chat.id(),
+                chat_id,
                 format!(
                     "This sticker pack is completely filled. \
                 Remove a few stickers from it and only then use /addstickers again."
                 ),
             )
-            .reply_parameters(ReplyParameters::new(message.id).chat_id(message.chat.id())),
+            .reply_parameters(ReplyParameters::new(message_id).chat_id(chat_id)),
         )
         .await?;
 
@@ -182,7 +232,13 @@ where
 
     fsm.set_value(
         "get_stolen_sticker_set",
-        (sticker_set_name, sticker_set.title, set_length),
+        (
+            sticker_set_name,
+            sticker_set.title,
+            sticker_set.sticker_type,
+            set_length,
+            max_set_length,
+        ),
     )
     .await
     .map_err(Into::into)?;
@@ -192,8 +248,11 @@ where
         .map_err(Into::into)?;
 
     bot.send(SendMessage::new(
-        message.chat.id(),
-        "Now send me the stickers that you want to add to your sticker pack. \
+        chat_id,
+        "Now send me the stickers that you want to add to your sticker pack, \
+        or links to sticker packs to add all their stickers. \
+        You can also send me photos or PNG, WebP and JPEG images to make stickers from them, \
+        the emoji from the caption will be used for the sticker. \
         When you're ready, use /done to add all selected stickers to the sticker pack. \
         You can also remove last sent sticker from the add list using /undo.",
     ))
@@ -211,7 +270,7 @@ where
 }
 
 pub async fn get_stickers_to_add<S, UoWFactory>(
-    bot: Bot,
+    Extension(bot): Extension<Scheduler>,
     message: MessageSticker,
     fsm: Context<S>,
 ) -> HandlerResult
@@ -219,7 +278,13 @@ where
     UoWFactory: UoWFactoryTrait,
     S: Storage,
 {
-    let (_, _, sticker_set_length): (Box<str>, Box<str>, usize) = fsm
+    let (target_set_name, _, sticker_set_type, sticker_set_length, max_set_length): (
+        Box<str>,
+        Box<str>,
+        Box<str>,
+        usize,
+        usize,
+    ) = fsm
         .get_value("get_stolen_sticker_set")
         .await
         .map_err(Into::into)?
@@ -241,6 +306,22 @@ where
         return Ok(EventReturn::Finish);
     }
 
+    // check it before any request, otherwise the whole job fails on this sticker
+    if let Err(err) = StickerKind::from(&*sticker_set_type).check(&sticker_to_add) {
+        bot.send(
+            SendMessage::new(
+                message.chat.id(),
+                format!("Sorry, but this sticker can't be added into your sticker pack: {err}. Try send another sticker."),
+            )
+            .reply_parameters(ReplyParameters::new(message.id).chat_id(message.chat.id())),
+        )
+        .await?;
+
+        return Ok(EventReturn::Finish);
+    }
+
+    let sticker_set_name = sticker_to_add.set_name.clone();
+
     let stickers_vec: Vec<Sticker> = match fsm
         .get_value::<&str, Vec<Sticker>>("get_stickers_to_add")
         .await
@@ -249,16 +330,8 @@ where
         Some(mut stickers_vec) => {
             let stickers_vec_len = stickers_vec.len();
 
-            if sticker_set_length + stickers_vec_len >= MAX_STICKER_SET_LENGTH {
-                bot.send(SendMessage::new(
-                    message.chat.id(),
-                    format!("The amount of stickers has reached {max_len}. Use /done to add all the selected stickers, or \
-                    /undo if you want to remove the latest stickers from the add list. All the following sent stickers will be ignored.", 
-                    max_len = html_code(MAX_STICKER_SET_LENGTH.to_string())),
-                ).parse_mode(ParseMode::HTML))
-                .await?;
-
-                return Ok(EventReturn::Finish);
+            if sticker_set_length + stickers_vec_len >= max_set_length {
+                return send_stickers_limit_message(&bot, message.chat.id(), max_set_length).await;
             }
 
             stickers_vec.push(sticker_to_add);
@@ -267,24 +340,336 @@ where
         None => vec![sticker_to_add],
     };
 
+    fsm.set_value("get_stickers_to_add", stickers_vec)
+        .await
+        .map_err(Into::into)?;
+
+    let processed_message = SendMessage::new(
+        message.chat.id(),
+        "Sticker processed! Send the next one or use the /done or /undo commands. \
+        You can also send me a link to a sticker pack to add all its stickers.",
+    )
+    .reply_parameters(ReplyParameters::new(message.id).chat_id(message.chat.id()));
+
+    let callback_data = sticker_set_name
+        .filter(|sticker_set_name| *sticker_set_name != target_set_name)
+        .map(|sticker_set_name| {
+            format!(
+                "{prefix}{sticker_set_name}",
+                prefix = CallbackDataPrefix::AddEntirePack.as_str()
+            )
+        })
+        .filter(|callback_data| callback_data.len() <= MAX_CALLBACK_DATA_LENGTH);
+
+    bot.send(match callback_data {
+        Some(callback_data) => processed_message.reply_markup(InlineKeyboardMarkup::new([[
+            InlineKeyboardButton::new("Add entire pack").callback_data(callback_data),
+        ]])),
+        None => processed_message,
+    })
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
+
+/// Make a sticker from the photo or the image document and put it into the add list
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
+pub async fn get_images_to_add<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    Extension(chat_locks): Extension<ChatLocks>,
+    message: Message,
+    fsm: Context<S>,
+) -> HandlerResult {
+    let (_, _, sticker_set_type, sticker_set_length, max_set_length): (
+        Box<str>,
+        Box<str>,
+        Box<str>,
+        usize,
+        usize,
+    ) = fsm
+        .get_value("get_stolen_sticker_set")
+        .await
+        .map_err(Into::into)?
+        // only panic if i'm forget call fsm.set_value() in function get_stolen_sticker_set()
+        .expect("sticker set name and sticker set title for sticker set should be set");
+
+    let chat_id = message.chat().id();
+
+    // check it before downloading the image, otherwise the whole job fails on this sticker
+    if StickerKind::from(&*sticker_set_type) == StickerKind::CustomEmoji {
+        bot.send(SendMessage::new(
+            chat_id,
+            "Sorry, but I can make only regular stickers from images, \
+            they can't be added into a custom emoji pack. Try send a custom emoji.",
+        ))
+        .await?;
+
+        return Ok(EventReturn::Finish);
+    }
+
+    let stickers_vec: Vec<Sticker> = fsm
+        .get_value("get_stickers_to_add")
+        .await
+        .map_err(Into::into)?
+        .unwrap_or_default();
+
+    if sticker_set_length + stickers_vec.len() >= max_set_length {
+        return send_stickers_limit_message(&bot, chat_id, max_set_length).await;
+    }
+
+    // only panic if messages uses in channels, but i'm using private filter
+    let user_id = message.from().expect("user not specified").id;
+
+    let sticker = match upload_sticker_image(&bot, &message, user_id).await? {
+        Some(sticker) => sticker,
+        // user is already notified about the error
+        None => return Ok(EventReturn::Finish),
+    };
+
+    // the images of an album are handled at the same time, so the list is read and written under the lock
+    // of the chat after the upload, otherwise the stickers added meanwhile are lost
+    let _chat_lock = chat_locks.lock(chat_id).await;
+    let mut stickers_vec: Vec<Sticker> = fsm
+        .get_value("get_stickers_to_add")
+        .await
+        .map_err(Into::into)?
+        .unwrap_or_default();
+
+    if sticker_set_length + stickers_vec.len() >= max_set_length {
+        return send_stickers_limit_message(&bot, chat_id, max_set_length).await;
+    }
+
+    stickers_vec.push(sticker);
+
     fsm.set_value("get_stickers_to_add", stickers_vec)
         .await
         .map_err(Into::into)?;
 
     bot.send(
         SendMessage::new(
-            message.chat.id(),
-            "Sticker processed! Send the next one or use the /done or /undo commands.",
+            chat_id,
+            "Sticker made from the image! Send the next one or use the /done or /undo commands.",
         )
-        .reply_parameters(ReplyParameters::new(message.id).chat_id(message.chat.id())),
+        .reply_parameters(ReplyParameters::new(message.id()).chat_id(chat_id)),
     )
     .await?;
 
     Ok(EventReturn::Finish)
 }
 
+/// Tell the user that the sticker pack with the stickers to add is full
+async fn send_stickers_limit_message(
+    bot: &Scheduler,
+    chat_id: i64,
+    max_set_length: usize,
+) -> HandlerResult {
+    bot.send(SendMessage::new(
+        chat_id,
+        format!("The amount of stickers has reached {max_len}. Use /done to add all the selected stickers, or \
+        /undo if you want to remove the latest stickers from the add list. All the following sent stickers will be ignored.",
+        max_len = html_code(max_set_length.to_string())),
+    ).parse_mode(ParseMode::HTML))
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
+
+pub async fn add_entire_sticker_set_from_text<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+) -> HandlerResult {
+    let sticker_set_name = match parse_sticker_set_name(&message.text) {
+        Some(sticker_set_name) => sticker_set_name,
+        None => {
+            bot.send(SendMessage::new(
+                message.chat.id(),
+                format!(
+                    "I can't find a sticker pack name in this message. Send me a sticker, \
+                    a link like {TELEGRAM_STICKER_SET_URL}<name> or use the /done or /undo commands."
+                ),
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    add_entire_sticker_set(&bot, &fsm, message.chat.id(), sticker_set_name).await
+}
+
+pub async fn process_add_entire_sticker_set_button<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    callback_query: CallbackQuery,
+    fsm: Context<S>,
+) -> HandlerResult {
+    let chat_id = match callback_query.chat_id() {
+        Some(chat_id) => chat_id,
+        None => return Ok(EventReturn::Finish),
+    };
+
+    bot.send(AnswerCallbackQuery::new(callback_query.id))
+        .await?;
+
+    // i guarantee that there will be `Some()`
+    let callback_data = callback_query.data.unwrap();
+    let sticker_set_name = callback_data
+        .strip_prefix(CallbackDataPrefix::AddEntirePack.as_str())
+        .expect("Failed to eat callback data prefix");
+
+    add_entire_sticker_set(&bot, &fsm, chat_id, sticker_set_name).await
+}
+
+/// Add all stickers of the sticker set `sticker_set_name`, that the user's sticker set and the add list don't have,
+/// into the add list, as many as fit into the user's sticker set, and report the ones that didn't fit
+async fn add_entire_sticker_set<S: Storage>(
+    bot: &Scheduler,
+    fsm: &Context<S>,
+    chat_id: i64,
+    sticker_set_name: &str,
+) -> HandlerResult {
+    let (target_set_name, _, sticker_set_type, sticker_set_length, max_set_length): (
+        Box<str>,
+        Box<str>,
+        Box<str>,
+        usize,
+        usize,
+    ) = fsm
+        .get_value("get_stolen_sticker_set")
+        .await
+        .map_err(Into::into)?
+        // only panic if i'm forget call fsm.set_value() in function get_stolen_sticker_set()
+        .expect("sticker set name and sticker set title for sticker set should be set");
+
+    if sticker_set_name == &*target_set_name {
+        bot.send(SendMessage::new(
+            chat_id,
+            "These are the stickers of the sticker pack you're adding stickers to. Try to send another sticker pack.",
+        ))
+        .await?;
+
+        return Ok(EventReturn::Finish);
+    }
+
+    let sticker_set = match bot.send(GetStickerSet::new(sticker_set_name)).await {
+        Ok(sticker_set) => sticker_set,
+        Err(ErrorKind::Telegram(TelegramErrorKind::BadRequest { message }))
+            if message.as_ref() == "Bad Request: STICKERSET_INVALID" =>
+        {
+            bot.send(SendMessage::new(
+                chat_id,
+                "Sticker pack with this name doesn't exist. Try to send another sticker pack.",
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+        Err(error) => {
+            error!(?error, "Error occurred while getting sticker set to add: ");
+
+            send_default_error_message(bot, chat_id).await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    // all stickers of a sticker set have the same type, so the first one is enough
+    if let Some(Err(err)) = sticker_set
+        .stickers
+        .first()
+        .map(|sticker| StickerKind::from(&*sticker_set_type).check(sticker))
+    {
+        bot.send(SendMessage::new(
+            chat_id,
+            format!(
+                "Sorry, but stickers of this pack can't be added into your sticker pack: {err}."
+            ),
+        ))
+        .await?;
+
+        return Ok(EventReturn::Finish);
+    }
+
+    let target_set = match bot.send(GetStickerSet::new(target_set_name.as_ref())).await {
+        Ok(target_set) => target_set,
+        Err(error) => {
+            error!(
+                ?error,
+                "Error occurred while getting sticker set to add into: "
+            );
+
+            send_default_error_message(bot, chat_id).await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    let mut stickers_vec: Vec<Sticker> = fsm
+        .get_value("get_stickers_to_add")
+        .await
+        .map_err(Into::into)?
+        .unwrap_or_default();
+
+    let sticker_set_len = sticker_set.stickers.len();
+    // the stickers, that are already in the user's sticker set or in the add list, would be duplicated
+    let mut new_stickers = missing_stickers(
+        missing_stickers(sticker_set.stickers, &target_set.stickers),
+        &stickers_vec,
+    );
+    let new_len = new_stickers.len();
+    let duplicates_len = sticker_set_len - new_len;
+
+    let remaining = max_set_length.saturating_sub(sticker_set_length + stickers_vec.len());
+    new_stickers.truncate(remaining);
+    let added_len = new_stickers.len();
+
+    stickers_vec.extend(new_stickers);
+    let stickers_vec_len = stickers_vec.len();
+
+    fsm.set_value("get_stickers_to_add", stickers_vec)
+        .await
+        .map_err(Into::into)?;
+
+    let title = html_code(html_quote(&sticker_set.title));
+
+    let mut text = if new_len == 0 {
+        format!(
+            "All stickers of {title} are already in your sticker pack or in the add list. \
+            Send the next sticker or pack, or use the /done or /undo commands."
+        )
+    } else if added_len == new_len {
+        format!(
+            "All {added_len} new stickers of {title} were added to the list, {stickers_vec_len} stickers to add in total. \
+            Send the next sticker or pack, or use the /done or /undo commands."
+        )
+    } else if added_len == 0 {
+        format!(
+            "Your sticker pack can't fit more stickers, so none of {title} stickers were added. \
+            Use /done to add the selected stickers or /undo to remove the latest ones."
+        )
+    } else {
+        format!(
+            "Only {added_len} of {new_len} new stickers of {title} fit into your sticker pack, \
+            so the last {skipped_len} of them were not added to the list. \
+            Use /done to add {stickers_vec_len} selected stickers or /undo to remove the latest ones.",
+            skipped_len = new_len - added_len
+        )
+    };
+    if duplicates_len > 0 && new_len > 0 {
+        text.push_str(&format!(
+            " {duplicates_len} stickers of it were skipped, because they are already in your sticker pack or in the add list."
+        ));
+    }
+
+    bot.send(SendMessage::new(chat_id, text).parse_mode(ParseMode::HTML))
+        .await?;
+
+    Ok(EventReturn::Finish)
+}
+
 pub async fn undo_last_sticker<S: Storage>(
-    bot: Bot,
+    Extension(bot): Extension<Scheduler>,
     message: MessageText,
     fsm: Context<S>,
 ) -> HandlerResult {
@@ -344,12 +729,24 @@ pub async fn undo_last_sticker<S: Storage>(
 
 /// ### Panics
 /// - Panics if user is unknown (only if message sent in channel)
-pub async fn add_stickers_to_user_owned_sticker_set<S: Storage>(
-    bot: Bot,
+pub async fn add_stickers_to_user_owned_sticker_set<S, UoWFactory>(
+    Extension(bot): Extension<Scheduler>,
     message: MessageText,
     fsm: Context<S>,
-) -> HandlerResult {
-    let (sticker_set_name, sticker_set_title, _): (Box<str>, Box<str>, usize) = fsm
+    Extension(job_queue): Extension<JobQueue>,
+    Extension(uow_factory): Extension<UoWFactory>,
+) -> HandlerResult
+where
+    S: Storage,
+    UoWFactory: UoWFactoryTrait,
+{
+    let (sticker_set_name, sticker_set_title, sticker_set_type, _, _): (
+        Box<str>,
+        Box<str>,
+        Box<str>,
+        usize,
+        usize,
+    ) = fsm
         .get_value("get_stolen_sticker_set")
         .await
         .map_err(Into::into)?
@@ -392,51 +789,46 @@ pub async fn add_stickers_to_user_owned_sticker_set<S: Storage>(
     } else {
         "ese stickers"
     };
-    let message_delete = bot
-        .send(
-            SendMessage::new(
-                message.chat.id(),
-                format!(
-                "Done! Trying to add th{these_or_this} to {your} sticker pack.. \
-                It may take up to a several minutes, if you have selected a lot of stickers to add.",
-                your = html_text_link("your", &sticker_set_name)),
-            )
-            .parse_mode(ParseMode::HTML),
-        )
-        .await?;
 
-    let all_stickers_was_added = add_stickers(&bot, user_id, sticker_set_name.as_ref(), stickers)
+    let position = match job_queue
+        .try_enqueue_add_stickers(
+            &uow_factory,
+            AddStickers {
+                tg_id: user_id,
+                short_name: sticker_set_name.to_string(),
+                title: sticker_set_title.to_string(),
+                sticker_type: sticker_set_type.to_string(),
+                stickers,
+            },
+        )
         .await
-        // cant panic because we checked above that we're have at least 1 sticker in this list
-        .expect("empty stickers list");
+    {
+        Ok(position) => position,
+        Err(err) => {
+            error!(?err, "Error occurred while enqueueing add stickers job: ");
 
-    // delete unnecessary message
-    bot.send(DeleteMessage::new(
-        message_delete.chat().id(),
-        message_delete.id(),
-    ))
-    .await?;
+            bot.send(SendMessage::new(
+                message.chat.id(),
+                "Sorry, I'm too busy right now. Please try to use /addstickers again in a few minutes.",
+            ))
+            .await?;
 
-    let stickers_was_added_msg = if all_stickers_was_added {
-        format!(
-            "Sticker(s) have been added into {set}!",
-            set = html_text_link(
-                html_quote(sticker_set_title),
-                format!("{TELEGRAM_STICKER_SET_URL}{}", sticker_set_name)
-            )
-        )
-    } else {
-        format!(
-            "Error occurred while adding stickers into {set}. Due to an error, not all specified stickers have been added.",
-            set = html_text_link(
-                html_quote(sticker_set_title),
-                format!("{TELEGRAM_STICKER_SET_URL}{}", sticker_set_name)
-            )
-        )
+            return Ok(EventReturn::Finish);
+        }
     };
 
     bot.send(
-        SendMessage::new(message.chat.id(), stickers_was_added_msg).parse_mode(ParseMode::HTML),
+        SendMessage::new(
+            message.chat.id(),
+            format!(
+                "Done! Trying to add th{these_or_this} to {your} sticker pack.. Your place in the queue: {position}. \
+                It may take up to a several minutes, if you have selected a lot of stickers to add. \
+                I'll send you a message when it's ready.",
+                your = html_text_link("your", format!("{TELEGRAM_STICKER_SET_URL}{}", sticker_set_name)),
+                position = html_code(position.to_string())
+            ),
+        )
+        .parse_mode(ParseMode::HTML),
     )
     .await?;
 
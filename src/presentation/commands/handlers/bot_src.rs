@@ -1,5 +1,5 @@
 use telers::{
-    Bot,
+    Extension,
     enums::ParseMode,
     event::{EventReturn, telegram::HandlerResult},
     fsm::{Context, Storage},
@@ -8,8 +8,10 @@ use telers::{
     utils::text::html_text_link,
 };
 
+use crate::presentation::scheduler::Scheduler;
+
 pub async fn source_handler<S: Storage>(
-    bot: Bot,
+    Extension(bot): Extension<Scheduler>,
     message: MessageText,
     fsm: Context<S>,
 ) -> HandlerResult {
@@ -1,13 +1,15 @@
 use telers::{
-    Bot,
+    Extension,
     event::{EventReturn, telegram::HandlerResult},
     fsm::{Context, Storage},
     methods::SendMessage,
     types::MessageText,
 };
 
+use crate::presentation::scheduler::Scheduler;
+
 pub async fn cancel_handler<S: Storage>(
-    bot: Bot,
+    Extension(bot): Extension<Scheduler>,
     message: MessageText,
     fsm: Context<S>,
 ) -> HandlerResult {
@@ -0,0 +1,303 @@
+use grammers_client::Client;
+use sqlx::{Database, Pool};
+use telers::{
+    Extension,
+    enums::ParseMode,
+    errors::{HandlerError, TelegramErrorKind, session::ErrorKind},
+    event::{EventReturn, telegram::HandlerResult},
+    fsm::{Context, Storage},
+    methods::{GetMe, GetStickerSet, SendMessage},
+    types::{MessageSticker, MessageText, ReplyParameters, Sticker},
+    utils::text::{html_quote, html_text_link},
+};
+use tracing::{debug, error};
+
+use crate::{
+    application::{
+        common::{
+            exceptions::RepoKind,
+            traits::uow::{UoW as _, UoWFactory as UoWFactoryTrait},
+        },
+        interactors::{create_set::create_set, set_deleted_col::set_deleted_col},
+        set::{
+            dto::{
+                create::Create as CreateSet, get_by_short_name::GetByShortName,
+                set_deleted_col_by_short_name::SetDeletedColByShortName,
+            },
+            repository::SetRepo,
+        },
+        steal_job::repository::StealJobRepo,
+        user::repository::UserRepo,
+    },
+    core::helpers::{common::set_created_by, constants::TELEGRAM_STICKER_SET_URL},
+    infrastructure::database::{
+        repositories::{set::SetRepoImpl, steal_job::StealJobRepoImpl, user::UserRepoImpl},
+        uow::UoWFactory,
+    },
+    presentation::{
+        commands::{common::replied_sticker, states::claim_sticker_set::ClaimStickerSetState},
+        scheduler::Scheduler,
+        telegram_application::get_sticker_set_user_id,
+    },
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClaimResult {
+    /// Sticker set was added into the database for the user `tg_id`
+    Claimed {
+        tg_id: i64,
+        title: String,
+    },
+    /// Sticker set is already in the database for the user it's claimed for
+    AlreadyClaimed,
+    NotCreatedByBot,
+    NotOwner,
+    NotExist,
+}
+
+/// Add sticker set `set_name`, that was created by the bot but is missing from the database
+/// (e.g. created before the database existed), for its owner.
+/// If `user_id` is set, the sticker set is added only if it belongs to this user.
+pub async fn claim_sticker_set<UoWFactory>(
+    bot: &Scheduler,
+    client: &Client,
+    uow_factory: &UoWFactory,
+    set_name: &str,
+    user_id: Option<i64>,
+) -> Result<ClaimResult, HandlerError>
+where
+    UoWFactory: UoWFactoryTrait,
+{
+    // cant panic because bot cant be without username
+    let bot_username = bot
+        .send(GetMe::new())
+        .await?
+        .username
+        .expect("bot without username :/");
+
+    if !set_created_by(set_name, &bot_username) {
+        return Ok(ClaimResult::NotCreatedByBot);
+    }
+
+    let mut uow = uow_factory.create_uow();
+
+    let deleted = match uow
+        .set_repo()
+        .await
+        .map_err(HandlerError::new)?
+        .get_one_by_short_name(GetByShortName::new(set_name))
+        .await
+    {
+        // the sticker set can be in the list of another user, so it isn't reported as the user's one
+        Ok(set) if !set.deleted && user_id.is_some_and(|user_id| user_id != set.tg_id) => {
+            return Ok(ClaimResult::NotOwner);
+        }
+        Ok(set) if !set.deleted => return Ok(ClaimResult::AlreadyClaimed),
+        // the sticker set could be marked as deleted by mistake, e.g. if Telegram was unavailable
+        Ok(_) => true,
+        Err(RepoKind::Exception(_)) => false,
+        Err(err) => return Err(HandlerError::new(err)),
+    };
+
+    let sticker_set = match bot.send(GetStickerSet::new(set_name)).await {
+        Ok(sticker_set) => sticker_set,
+        Err(ErrorKind::Telegram(TelegramErrorKind::BadRequest { message }))
+            if message.as_ref() == "Bad Request: STICKERSET_INVALID" =>
+        {
+            return Ok(ClaimResult::NotExist);
+        }
+        Err(error) => return Err(error.into()),
+    };
+
+    let owner_id = get_sticker_set_user_id(set_name, client)
+        .await
+        .map_err(HandlerError::new)?;
+
+    if user_id.is_some_and(|user_id| user_id != owner_id) {
+        return Ok(ClaimResult::NotOwner);
+    }
+
+    if deleted {
+        set_deleted_col(&mut uow, SetDeletedColByShortName::new(set_name, false))
+            .await
+            .map_err(HandlerError::new)?;
+    } else {
+        create_set(
+            &mut uow,
+            // we don't know where the stickers of the sticker set come from
+            CreateSet::new(
+                owner_id,
+                set_name,
+                &sticker_set.title,
+                None,
+                None,
+                sticker_set.stickers.len() as i32,
+            ),
+        )
+        .await
+        .map_err(HandlerError::new)?;
+    }
+
+    Ok(ClaimResult::Claimed {
+        tg_id: owner_id,
+        title: sticker_set.title.to_string(),
+    })
+}
+
+/// Add sticker sets `short_names` for their owners, it's used by the admin command of the CLI
+pub async fn claim_sticker_sets<DB>(
+    pool: Pool<DB>,
+    bot: Scheduler,
+    client: &Client,
+    short_names: &[String],
+) where
+    DB: Database,
+    for<'a> UserRepoImpl<&'a mut DB::Connection>: UserRepo,
+    for<'a> SetRepoImpl<&'a mut DB::Connection>: SetRepo,
+    for<'a> StealJobRepoImpl<&'a mut DB::Connection>: StealJobRepo,
+{
+    let uow_factory = UoWFactory::new(pool);
+
+    for short_name in short_names {
+        match claim_sticker_set(&bot, client, &uow_factory, short_name, None).await {
+            Ok(ClaimResult::Claimed { tg_id, .. }) => {
+                debug!(short_name, tg_id, "Sticker set was claimed for its owner");
+            }
+            Ok(result) => error!(short_name, ?result, "Sticker set was not claimed: "),
+            Err(err) => error!(
+                short_name,
+                ?err,
+                "Error occurred while claiming sticker set: "
+            ),
+        }
+    }
+}
+
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
+pub async fn claim_handler<S, UoWFactory>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+    Extension(client): Extension<Client>,
+    Extension(uow_factory): Extension<UoWFactory>,
+) -> HandlerResult
+where
+    S: Storage,
+    UoWFactory: UoWFactoryTrait,
+{
+    fsm.finish().await.map_err(Into::into)?;
+
+    fsm.set_state(ClaimStickerSetState::GetStickerSet)
+        .await
+        .map_err(Into::into)?;
+
+    if let Some((reply_id, sticker)) = replied_sticker(&message) {
+        return process_sticker_set_to_claim(
+            &bot,
+            &client,
+            &uow_factory,
+            message.chat.id(),
+            reply_id,
+            // only panic if messages uses in channels, but i'm using private filter
+            message.from.expect("user not specified").id,
+            sticker,
+        )
+        .await;
+    }
+
+    bot.send(SendMessage::new(
+        message.chat.id(),
+        "If your sticker pack was made by me, but it's missing from /mystickers, \
+        send me a sticker from it and I'll add it to your list:",
+    ))
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
+
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
+pub async fn get_sticker_set_to_claim<UoWFactory>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageSticker,
+    Extension(client): Extension<Client>,
+    Extension(uow_factory): Extension<UoWFactory>,
+) -> HandlerResult
+where
+    UoWFactory: UoWFactoryTrait,
+{
+    process_sticker_set_to_claim(
+        &bot,
+        &client,
+        &uow_factory,
+        message.chat.id(),
+        message.id,
+        // only panic if messages uses in channels, but i'm using private filter
+        message.from.expect("user not specified").id,
+        &message.sticker,
+    )
+    .await
+}
+
+async fn process_sticker_set_to_claim<UoWFactory>(
+    bot: &Scheduler,
+    client: &Client,
+    uow_factory: &UoWFactory,
+    chat_id: i64,
+    message_id: i64,
+    user_id: i64,
+    sticker: &Sticker,
+) -> HandlerResult
+where
+    UoWFactory: UoWFactoryTrait,
+{
+    let set_name = match sticker.set_name.as_deref() {
+        Some(set_name) => set_name,
+        None => {
+            bot.send(SendMessage::new(
+                chat_id,
+                "This sticker is without sticker pack. Try to send another sticker.",
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    let text = match claim_sticker_set(bot, client, uow_factory, set_name, Some(user_id)).await {
+        Ok(ClaimResult::Claimed { title, .. }) => format!(
+            "Done! {link} is in your /mystickers list now. Send the next one or use /cancel.",
+            link = html_text_link(
+                html_quote(&title),
+                format!("{TELEGRAM_STICKER_SET_URL}{set_name}")
+            )
+        ),
+        Ok(ClaimResult::AlreadyClaimed) => {
+            "This sticker pack is already in your /mystickers list.".to_owned()
+        }
+        Ok(ClaimResult::NotCreatedByBot) => "This sticker pack wasn't made by me. \
+            You can steal it using /stealpack."
+            .to_owned(),
+        Ok(ClaimResult::NotOwner) => "You are not the owner of this sticker pack.".to_owned(),
+        Ok(ClaimResult::NotExist) => "This sticker pack doesn't exist anymore.".to_owned(),
+        Err(error) => {
+            error!(
+                ?error,
+                ?set_name,
+                "Error occurred while claiming sticker set: "
+            );
+
+            "Sorry, an error occurred while adding this sticker pack. Try again later.".to_owned()
+        }
+    };
+
+    bot.send(
+        SendMessage::new(chat_id, text)
+            .parse_mode(ParseMode::HTML)
+            .reply_parameters(ReplyParameters::new(message_id).chat_id(chat_id)),
+    )
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
@@ -1,11 +1,16 @@
 use telers::{
-    Bot,
+    Extension,
     event::{EventReturn, telegram::HandlerResult},
     methods::SendMessage,
     types::Message,
 };
 
-pub async fn process_non_sticker_handler(bot: Bot, message: Message) -> HandlerResult {
+use crate::presentation::scheduler::Scheduler;
+
+pub async fn process_non_sticker_handler(
+    Extension(bot): Extension<Scheduler>,
+    message: Message,
+) -> HandlerResult {
     bot.send(SendMessage::new(
         message.chat().id(),
         "Please send me a sticker.",
@@ -15,7 +20,10 @@ pub async fn process_non_sticker_handler(bot: Bot, message: Message) -> HandlerR
     Ok(EventReturn::Finish)
 }
 
-pub async fn process_non_text_handler(bot: Bot, message: Message) -> HandlerResult {
+pub async fn process_non_text_handler(
+    Extension(bot): Extension<Scheduler>,
+    message: Message,
+) -> HandlerResult {
     bot.send(SendMessage::new(
         message.chat().id(),
         "Please send me a text message.",
@@ -0,0 +1,289 @@
+use telers::{
+    Extension,
+    enums::ParseMode,
+    errors::{HandlerError, TelegramErrorKind, session::ErrorKind},
+    event::{EventReturn, telegram::HandlerResult},
+    fsm::{Context, Storage},
+    methods::{AnswerCallbackQuery, DeleteStickerSet, EditMessageText, SendMessage},
+    types::{CallbackQuery, InlineKeyboardButton, InlineKeyboardMarkup, MessageText},
+    utils::text::{html_quote, html_text_link},
+};
+use tracing::error;
+
+use crate::{
+    application::{
+        common::traits::uow::{UoW as _, UoWFactory as UoWFactoryTrait},
+        interactors::set_deleted_col::set_deleted_col,
+        set::{
+            dto::{
+                get_by_tg_id::GetByTgID as GetSetByTgID,
+                set_deleted_col_by_short_name::SetDeletedColByShortName,
+            },
+            repository::SetRepo as _,
+        },
+    },
+    core::helpers::constants::{DELETE_SETS_NUMBER_PER_PAGE, TELEGRAM_STICKER_SET_URL},
+    presentation::{
+        commands::states::{
+            callback_data::CallbackDataPrefix, delete_sticker_set::DeleteStickerSetState,
+        },
+        scheduler::Scheduler,
+    },
+};
+
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
+pub async fn delete_pack_handler<S, UoWFactory>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+    Extension(uow_factory): Extension<UoWFactory>,
+) -> HandlerResult
+where
+    S: Storage,
+    UoWFactory: UoWFactoryTrait,
+{
+    fsm.finish().await.map_err(Into::into)?;
+
+    let mut uow = uow_factory.create_uow();
+
+    let sticker_sets: Vec<(Box<str>, Box<str>)> = uow
+        .set_repo()
+        .await
+        .map_err(HandlerError::new)?
+        .get_by_tg_id(GetSetByTgID::new(
+            // only panic if messages uses in channels, but i'm using private filter
+            message.from.expect("user not specified").id,
+            Some(false),
+        ))
+        .await
+        .map_err(HandlerError::new)?
+        .into_iter()
+        .map(|set| (set.short_name.into(), set.title.into()))
+        .collect();
+
+    if sticker_sets.is_empty() {
+        bot.send(SendMessage::new(
+            message.chat.id(),
+            "You don't have a single stolen sticker pack, so there is nothing to delete.",
+        ))
+        .await?;
+
+        return Ok(EventReturn::Finish);
+    }
+
+    bot.send(
+        SendMessage::new(
+            message.chat.id(),
+            "Choose the sticker pack you want to delete:",
+        )
+        .reply_markup(select_sticker_set_keyboard(&sticker_sets, 0)),
+    )
+    .await?;
+
+    fsm.set_value("delete_sticker_sets", sticker_sets)
+        .await
+        .map_err(Into::into)?;
+
+    fsm.set_state(DeleteStickerSetState::SelectStickerSet)
+        .await
+        .map_err(Into::into)?;
+
+    Ok(EventReturn::Finish)
+}
+
+/// Keyboard with the sticker sets of the `page` to choose one of them.
+/// Callback data: `p<page>` to switch the page, `s<index>` to choose the sticker set.
+fn select_sticker_set_keyboard(
+    sticker_sets: &[(Box<str>, Box<str>)],
+    page: usize,
+) -> InlineKeyboardMarkup {
+    let prefix = CallbackDataPrefix::DeletePack.as_str();
+    let begin = page * DELETE_SETS_NUMBER_PER_PAGE;
+    let end = (begin + DELETE_SETS_NUMBER_PER_PAGE).min(sticker_sets.len());
+
+    let mut buttons: Vec<Vec<InlineKeyboardButton>> = sticker_sets[begin..end]
+        .iter()
+        .enumerate()
+        .map(|(index, (_, title))| {
+            vec![
+                InlineKeyboardButton::new(title.as_ref())
+                    .callback_data(format!("{prefix}s{}", begin + index)),
+            ]
+        })
+        .collect();
+
+    let mut navigation = Vec::new();
+    if page > 0 {
+        navigation.push(
+            InlineKeyboardButton::new("<< Previous")
+                .callback_data(format!("{prefix}p{}", page - 1)),
+        );
+    }
+    if end < sticker_sets.len() {
+        navigation.push(
+            InlineKeyboardButton::new("Next >>").callback_data(format!("{prefix}p{}", page + 1)),
+        );
+    }
+    if !navigation.is_empty() {
+        buttons.push(navigation);
+    }
+
+    InlineKeyboardMarkup::new(buttons)
+}
+
+fn confirm_keyboard() -> InlineKeyboardMarkup {
+    let prefix = CallbackDataPrefix::DeletePack.as_str();
+
+    InlineKeyboardMarkup::new([[
+        InlineKeyboardButton::new("Yes, delete it").callback_data(format!("{prefix}y")),
+        InlineKeyboardButton::new("No").callback_data(format!("{prefix}n")),
+    ]])
+}
+
+pub async fn process_delete_pack_buttons<S, UoWFactory>(
+    Extension(bot): Extension<Scheduler>,
+    callback_query: CallbackQuery,
+    fsm: Context<S>,
+    Extension(uow_factory): Extension<UoWFactory>,
+) -> HandlerResult
+where
+    S: Storage,
+    UoWFactory: UoWFactoryTrait,
+{
+    let (chat_id, message_id) = match (callback_query.chat_id(), callback_query.message_id()) {
+        (Some(chat_id), Some(message_id)) => (chat_id, message_id),
+        _ => return Ok(EventReturn::Finish),
+    };
+
+    // i guarantee that there will be `Some()`
+    let message_data = callback_query.data.unwrap();
+    let action = message_data
+        .strip_prefix(CallbackDataPrefix::DeletePack.as_str())
+        .expect("Failed to eat callback data prefix");
+
+    bot.send(AnswerCallbackQuery::new(callback_query.id))
+        .await?;
+
+    let sticker_sets: Vec<(Box<str>, Box<str>)> = fsm
+        .get_value("delete_sticker_sets")
+        .await
+        .map_err(Into::into)?
+        // only panic if i'm forget call fsm.set_value() in function delete_pack_handler()
+        .expect("Sticker sets of the user should be set");
+
+    if let Some(page) = action.strip_prefix('p') {
+        let page = match page.parse::<usize>() {
+            Ok(page) if page * DELETE_SETS_NUMBER_PER_PAGE < sticker_sets.len() => page,
+            _ => return Ok(EventReturn::Finish),
+        };
+
+        bot.send(
+            EditMessageText::new("Choose the sticker pack you want to delete:")
+                .chat_id(chat_id)
+                .message_id(message_id)
+                .reply_markup(select_sticker_set_keyboard(&sticker_sets, page)),
+        )
+        .await?;
+
+        return Ok(EventReturn::Finish);
+    }
+
+    if let Some(index) = action.strip_prefix('s') {
+        let (short_name, title) = match index
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| sticker_sets.get(index))
+        {
+            Some(sticker_set) => sticker_set,
+            None => return Ok(EventReturn::Finish),
+        };
+
+        bot.send(
+            EditMessageText::new(format!(
+                "Are you sure you want to delete {link}? All its stickers will be deleted for everyone, \
+                it can't be undone.",
+                link = html_text_link(
+                    html_quote(title),
+                    format!("{TELEGRAM_STICKER_SET_URL}{short_name}")
+                )
+            ))
+            .chat_id(chat_id)
+            .message_id(message_id)
+            .parse_mode(ParseMode::HTML)
+            .reply_markup(confirm_keyboard()),
+        )
+        .await?;
+
+        fsm.set_value("delete_sticker_set_name", short_name.as_ref())
+            .await
+            .map_err(Into::into)?;
+
+        fsm.set_state(DeleteStickerSetState::Confirm)
+            .await
+            .map_err(Into::into)?;
+
+        return Ok(EventReturn::Finish);
+    }
+
+    let set_name: Box<str> = match fsm
+        .get_value("delete_sticker_set_name")
+        .await
+        .map_err(Into::into)?
+    {
+        Some(set_name) => set_name,
+        // confirmation buttons are shown only after choosing the sticker set
+        None => return Ok(EventReturn::Finish),
+    };
+
+    fsm.finish().await.map_err(Into::into)?;
+
+    if action != "y" {
+        bot.send(
+            EditMessageText::new("Okay, the sticker pack was not deleted.")
+                .chat_id(chat_id)
+                .message_id(message_id),
+        )
+        .await?;
+
+        return Ok(EventReturn::Finish);
+    }
+
+    match bot.send(DeleteStickerSet::new(set_name.as_ref())).await {
+        Ok(_) => (),
+        // the sticker set was already deleted via @Stickers, only our database doesn't know about it yet
+        Err(ErrorKind::Telegram(TelegramErrorKind::BadRequest { message }))
+            if message.as_ref() == "Bad Request: STICKERSET_INVALID" => {}
+        Err(error) => {
+            error!(
+                ?error,
+                ?set_name,
+                "Error occurred while deleting sticker set: "
+            );
+
+            bot.send(
+                EditMessageText::new("Sorry, an error occurred while deleting the sticker pack.")
+                    .chat_id(chat_id)
+                    .message_id(message_id),
+            )
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    }
+
+    let mut uow = uow_factory.create_uow();
+
+    set_deleted_col(&mut uow, SetDeletedColByShortName::new(&set_name, true))
+        .await
+        .map_err(HandlerError::new)?;
+
+    bot.send(
+        EditMessageText::new("The sticker pack was deleted.")
+            .chat_id(chat_id)
+            .message_id(message_id),
+    )
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
@@ -3,8 +3,8 @@
 use chrono::{Duration, Utc};
 use sqlx::{Database, Pool};
 use telers::errors::{SessionErrorKind, TelegramErrorKind};
+use telers::event::simple::HandlerResult;
 use telers::methods::GetStickerSet;
-use telers::{Bot, event::simple::HandlerResult};
 use tracing::{debug, error};
 
 use crate::application::common::traits::uow::UoW as _;
@@ -13,18 +13,20 @@ use crate::application::set::dto::get_all::GetAll;
 use crate::application::set::dto::set_deleted_col_by_short_name::SetDeletedColByShortName;
 use crate::application::{
     common::traits::uow::UoWFactory as UoWFactoryTrait, set::repository::SetRepo,
-    user::repository::UserRepo,
+    steal_job::repository::StealJobRepo, user::repository::UserRepo,
 };
 use crate::infrastructure::database::{
-    repositories::{set::SetRepoImpl, user::UserRepoImpl},
+    repositories::{set::SetRepoImpl, steal_job::StealJobRepoImpl, user::UserRepoImpl},
     uow::UoWFactory,
 };
+use crate::presentation::scheduler::Scheduler;
 
-pub async fn deleted_sets_upd<DB>(pool: Pool<DB>, bot: Bot) -> HandlerResult
+pub async fn deleted_sets_upd<DB>(pool: Pool<DB>, bot: Scheduler) -> HandlerResult
 where
     DB: Database,
     for<'a> UserRepoImpl<&'a mut DB::Connection>: UserRepo,
     for<'a> SetRepoImpl<&'a mut DB::Connection>: SetRepo,
+    for<'a> StealJobRepoImpl<&'a mut DB::Connection>: StealJobRepo,
 {
     tokio::spawn(async move {
         let uow_factory = UoWFactory::new(pool.clone());
@@ -60,7 +62,7 @@ where
             }
 
             let sets = result.unwrap();
-            for (i, set) in sets.into_iter().enumerate() {
+            for set in sets {
                 if let Err(err) = bot.send(GetStickerSet::new(set.short_name.as_str())).await {
                     if matches!(err,  SessionErrorKind::Telegram(TelegramErrorKind::BadRequest { ref message })
                         if message.as_ref() == "Bad Request: STICKERSET_INVALID")
@@ -85,9 +87,6 @@ where
                         error!(?err, ?set.short_name, "Failed to get sticker set :",);
                     }
                 }
-                if i % 5 == 0 {
-                    tokio::time::sleep(tokio::time::Duration::from_millis(1500)).await;
-                }
             }
             last_upd_time = Utc::now();
             debug!(
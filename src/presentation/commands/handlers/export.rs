@@ -0,0 +1,199 @@
+use telers::{
+    Extension,
+    enums::ParseMode,
+    errors::{TelegramErrorKind, session::ErrorKind},
+    event::{EventReturn, telegram::HandlerResult},
+    fsm::{Context, Storage},
+    methods::{GetStickerSet, SendMessage},
+    types::{MessageSticker, MessageText, ReplyParameters},
+    utils::text::{html_code, html_quote, html_text_link},
+};
+use tracing::error;
+
+use crate::{
+    core::helpers::{common::parse_sticker_set_name, constants::TELEGRAM_STICKER_SET_URL},
+    presentation::{
+        commands::{
+            common::{replied_sticker, send_default_error_message},
+            states::export_sticker_set::ExportStickerSetState,
+        },
+        jobs::{ExportStickerSet, Job, JobQueue},
+        scheduler::Scheduler,
+    },
+};
+
+pub async fn export_handler<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+    Extension(job_queue): Extension<JobQueue>,
+) -> HandlerResult {
+    fsm.finish().await.map_err(Into::into)?;
+
+    if let Some((reply_id, sticker)) = replied_sticker(&message) {
+        return process_sticker_set_to_export(
+            &bot,
+            &job_queue,
+            message.chat.id(),
+            reply_id,
+            sticker.set_name.as_deref(),
+        )
+        .await;
+    }
+
+    fsm.set_state(ExportStickerSetState::GetStickerSet)
+        .await
+        .map_err(Into::into)?;
+
+    bot.send(SendMessage::new(
+        message.chat.id(),
+        format!(
+            "Send me a sticker or a link like {TELEGRAM_STICKER_SET_URL}<name> to the sticker pack \
+            that you want to export. I'll send you a ZIP archive with all its stickers."
+        ),
+    ))
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
+
+pub async fn get_sticker_set_to_export<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageSticker,
+    fsm: Context<S>,
+    Extension(job_queue): Extension<JobQueue>,
+) -> HandlerResult {
+    fsm.finish().await.map_err(Into::into)?;
+
+    process_sticker_set_to_export(
+        &bot,
+        &job_queue,
+        message.chat.id(),
+        message.id,
+        message.sticker.set_name.as_deref(),
+    )
+    .await
+}
+
+pub async fn get_sticker_set_to_export_from_text<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+    Extension(job_queue): Extension<JobQueue>,
+) -> HandlerResult {
+    let set_name = match parse_sticker_set_name(&message.text) {
+        Some(set_name) => set_name,
+        None => {
+            bot.send(SendMessage::new(
+                message.chat.id(),
+                format!(
+                    "I can't find a sticker pack name in this message. Send me a sticker, \
+                    a link like {TELEGRAM_STICKER_SET_URL}<name> or only the name of the sticker pack."
+                ),
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    fsm.finish().await.map_err(Into::into)?;
+
+    process_sticker_set_to_export(
+        &bot,
+        &job_queue,
+        message.chat.id(),
+        message.id,
+        Some(set_name),
+    )
+    .await
+}
+
+/// Put the job to send the user ZIP archive with all stickers of the sticker set `set_name`
+/// and `pack.json` manifest into the queue
+async fn process_sticker_set_to_export(
+    bot: &Scheduler,
+    job_queue: &JobQueue,
+    chat_id: i64,
+    message_id: i64,
+    set_name: Option<&str>,
+) -> HandlerResult {
+    let set_name = match set_name {
+        Some(set_name) => set_name,
+        None => {
+            bot.send(SendMessage::new(
+                chat_id,
+                "This sticker is without sticker pack. Try /export again with another sticker.",
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    let sticker_set = match bot.send(GetStickerSet::new(set_name)).await {
+        Ok(sticker_set) => sticker_set,
+        Err(ErrorKind::Telegram(TelegramErrorKind::BadRequest { message }))
+            if message.as_ref() == "Bad Request: STICKERSET_INVALID" =>
+        {
+            bot.send(SendMessage::new(
+                chat_id,
+                "Sticker pack with this name doesn't exist. Try /export again with another sticker pack.",
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+        Err(error) => {
+            error!(
+                ?error,
+                "Error occurred while getting sticker set to export: "
+            );
+
+            send_default_error_message(bot, chat_id).await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    let link = html_text_link(
+        html_quote(&sticker_set.title),
+        format!("{TELEGRAM_STICKER_SET_URL}{set_name}"),
+    );
+    let stickers_len = sticker_set.stickers.len();
+
+    let position = match job_queue.try_enqueue(Job::ExportStickerSet(ExportStickerSet {
+        chat_id,
+        sticker_set,
+    })) {
+        Ok(position) => position,
+        Err(err) => {
+            error!(?err, "Error occurred while enqueueing export job: ");
+
+            bot.send(SendMessage::new(
+                chat_id,
+                "Sorry, I'm too busy right now. Please try to use /export again in a few minutes.",
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    bot.send(
+        SendMessage::new(
+            chat_id,
+            format!(
+                "Exporting {stickers_len} stickers of {link}.. Your place in the queue: {position}. \
+                It may take up to a several minutes. I'll send you the archive when it's ready.",
+                stickers_len = html_code(stickers_len.to_string()),
+                position = html_code(position.to_string())
+            ),
+        )
+        .parse_mode(ParseMode::HTML)
+        .reply_parameters(ReplyParameters::new(message_id).chat_id(chat_id)),
+    )
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
@@ -0,0 +1,201 @@
+use telers::{
+    Extension,
+    enums::ParseMode,
+    errors::{HandlerError, TelegramErrorKind, session::ErrorKind},
+    event::{EventReturn, telegram::HandlerResult},
+    fsm::{Context, Storage},
+    methods::{GetStickerSet, SendMessage},
+    types::{MessageSticker, MessageText, ReplyParameters},
+    utils::text::{html_quote, html_text_link},
+};
+
+use crate::{
+    application::{
+        common::{
+            exceptions::RepoKind,
+            traits::uow::{UoW as _, UoWFactory as UoWFactoryTrait},
+        },
+        interactors::{
+            set_follow_source_col::set_follow_source_col,
+            update_source_sticker_ids::update_source_sticker_ids,
+        },
+        set::{
+            dto::{
+                get_by_short_name::GetByShortName,
+                set_follow_source_col_by_short_name::SetFollowSourceColByShortName,
+                update_source_sticker_ids::UpdateSourceStickerIds,
+            },
+            repository::SetRepo as _,
+        },
+    },
+    core::helpers::constants::{FOLLOW_SOURCE_CHECK_INTERVAL, TELEGRAM_STICKER_SET_URL},
+    presentation::{commands::states::follow_source::FollowSourceState, scheduler::Scheduler},
+};
+
+pub async fn follow_handler<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+) -> HandlerResult {
+    fsm.finish().await.map_err(Into::into)?;
+
+    fsm.set_state(FollowSourceState::GetStolenStickerSet)
+        .await
+        .map_err(Into::into)?;
+
+    bot.send(SendMessage::new(
+        message.chat.id(),
+        "Send me a sticker from your stolen sticker pack. If it doesn't follow its source sticker pack yet, \
+        I'll add new stickers of the source pack into it. Otherwise, I'll stop doing it.",
+    ))
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
+
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
+pub async fn toggle_follow_source<S, UoWFactory>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageSticker,
+    fsm: Context<S>,
+    Extension(uow_factory): Extension<UoWFactory>,
+) -> HandlerResult
+where
+    S: Storage,
+    UoWFactory: UoWFactoryTrait,
+{
+    let sticker_set_name = match message.sticker.set_name {
+        Some(sticker_set_name) => sticker_set_name,
+        None => {
+            bot.send(SendMessage::new(
+                message.chat.id(),
+                "This sticker is without sticker pack. Try to send another sticker pack.",
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    let mut uow = uow_factory.create_uow();
+
+    let set = match uow
+        .set_repo()
+        .await
+        .map_err(HandlerError::new)?
+        .get_one_by_short_name(GetByShortName::new(&sticker_set_name))
+        .await
+    {
+        Ok(set) => set,
+        Err(RepoKind::Exception(_)) => {
+            bot.send(SendMessage::new(
+                message.chat.id(),
+                "This sticker pack wasn't stolen by me. \
+                You can view all your stolen stickers using /mystickers or steal this pack using /stealpack.",
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+        Err(err) => return Err(HandlerError::new(err)),
+    };
+
+    // only panic if messages uses in channels, but i'm using private filter
+    let user_id = message.from.expect("user not specified").id;
+
+    if set.tg_id != user_id {
+        bot.send(SendMessage::new(
+            message.chat.id(),
+            "You are not the owner of this sticker pack. Please send me your sticker pack that stolen by me.",
+        ))
+        .await?;
+
+        return Ok(EventReturn::Finish);
+    }
+
+    let source_short_name = match set.source_short_name {
+        Some(source_short_name) => source_short_name,
+        None => {
+            bot.send(SendMessage::new(
+                message.chat.id(),
+                "I don't know the source of this sticker pack, so it can't follow it. \
+                Merged sticker packs and packs stolen long time ago can't follow their sources.",
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    let follow_source = !set.follow_source;
+
+    if follow_source {
+        let source = match bot
+            .send(GetStickerSet::new(source_short_name.as_str()))
+            .await
+        {
+            Ok(source) => source,
+            Err(ErrorKind::Telegram(TelegramErrorKind::BadRequest { message: error }))
+                if error.as_ref() == "Bad Request: STICKERSET_INVALID" =>
+            {
+                bot.send(SendMessage::new(
+                    message.chat.id(),
+                    "The source sticker pack doesn't exist anymore, so this sticker pack can't follow it.",
+                ))
+                .await?;
+
+                return Ok(EventReturn::Finish);
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        // the stickers that the source has now aren't added, even if they were removed from the pack
+        let source_sticker_ids: Vec<String> = source
+            .stickers
+            .into_iter()
+            .map(|sticker| sticker.file_unique_id.into())
+            .collect();
+
+        update_source_sticker_ids(
+            &mut uow,
+            UpdateSourceStickerIds::new(&set.short_name, &source_sticker_ids),
+        )
+        .await
+        .map_err(HandlerError::new)?;
+    }
+
+    set_follow_source_col(
+        &mut uow,
+        SetFollowSourceColByShortName::new(&set.short_name, follow_source),
+    )
+    .await
+    .map_err(HandlerError::new)?;
+
+    fsm.finish().await.map_err(Into::into)?;
+
+    let source = html_text_link(
+        html_quote(&source_short_name),
+        format!("{TELEGRAM_STICKER_SET_URL}{source_short_name}"),
+    );
+
+    let text = if follow_source {
+        format!(
+            "Now this sticker pack follows {source}. \
+            Every {FOLLOW_SOURCE_CHECK_INTERVAL} hours I'll add the new stickers that appear in it \
+            into your pack and let you know. \
+            Use /follow again to stop it."
+        )
+    } else {
+        format!("This sticker pack doesn't follow {source} anymore.")
+    };
+
+    bot.send(
+        SendMessage::new(message.chat.id(), text)
+            .parse_mode(ParseMode::HTML)
+            .reply_parameters(ReplyParameters::new(message.id).chat_id(message.chat.id())),
+    )
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
@@ -0,0 +1,265 @@
+use chrono::{Duration, Utc};
+use sqlx::{Database, Pool};
+use telers::{
+    enums::ParseMode,
+    errors::{SessionErrorKind, TelegramErrorKind},
+    event::simple::HandlerResult,
+    methods::{GetStickerSet, SendMessage},
+    utils::text::{html_code, html_quote, html_text_link},
+};
+use tracing::{debug, error};
+
+use crate::{
+    application::{
+        common::traits::uow::{UoW as _, UoWFactory as UoWFactoryTrait},
+        interactors::{
+            set_follow_source_col::set_follow_source_col,
+            update_source_sticker_ids::update_source_sticker_ids,
+        },
+        set::{
+            dto::{
+                set_follow_source_col_by_short_name::SetFollowSourceColByShortName,
+                update_source_sticker_ids::UpdateSourceStickerIds,
+            },
+            repository::SetRepo,
+        },
+        steal_job::repository::StealJobRepo,
+        user::repository::UserRepo,
+    },
+    core::helpers::{
+        common::{max_sticker_set_length, missing_stickers},
+        constants::{FOLLOW_SOURCE_CHECK_INTERVAL, TELEGRAM_STICKER_SET_URL},
+    },
+    infrastructure::database::{
+        repositories::{set::SetRepoImpl, steal_job::StealJobRepoImpl, user::UserRepoImpl},
+        uow::UoWFactory,
+    },
+    presentation::{
+        jobs::{AddStickers, JobQueue},
+        scheduler::Scheduler,
+    },
+};
+
+/// Periodically add new stickers of the source sticker sets into the sticker sets, that follow them
+pub async fn followed_sets_upd<DB>(
+    pool: Pool<DB>,
+    bot: Scheduler,
+    job_queue: JobQueue,
+) -> HandlerResult
+where
+    DB: Database,
+    for<'a> UserRepoImpl<&'a mut DB::Connection>: UserRepo,
+    for<'a> SetRepoImpl<&'a mut DB::Connection>: SetRepo,
+    for<'a> StealJobRepoImpl<&'a mut DB::Connection>: StealJobRepo,
+{
+    tokio::spawn(async move {
+        let uow_factory = UoWFactory::new(pool.clone());
+        let mut last_upd_time = Utc::now();
+
+        debug!("Start checking for new stickers in source sets.");
+
+        loop {
+            if Utc::now() - last_upd_time < Duration::hours(FOLLOW_SOURCE_CHECK_INTERVAL) {
+                tokio::time::sleep(tokio::time::Duration::from_secs(600)).await;
+                continue;
+            }
+
+            let mut uow = uow_factory.create_uow();
+
+            let sets = match uow.set_repo().await {
+                Ok(repo) => repo.get_following_source().await,
+                Err(err) => {
+                    error!(?err, "Failed to start transaction: ");
+                    tokio::time::sleep(tokio::time::Duration::from_secs(60)).await;
+                    continue;
+                }
+            };
+
+            let sets = match sets {
+                Ok(sets) => sets,
+                Err(err) => {
+                    error!(?err, "Error occurred while trying to get following sets: ");
+                    tokio::time::sleep(tokio::time::Duration::from_secs(60)).await;
+                    continue;
+                }
+            };
+
+            for set in sets {
+                // only sets with a source can follow it
+                let source_short_name = match set.source_short_name.as_deref() {
+                    Some(source_short_name) => source_short_name,
+                    None => continue,
+                };
+
+                let source_link = html_text_link(
+                    html_quote(source_short_name),
+                    format!("{TELEGRAM_STICKER_SET_URL}{source_short_name}"),
+                );
+
+                let source = match bot.send(GetStickerSet::new(source_short_name)).await {
+                    Ok(source) => source,
+                    Err(err) => {
+                        if matches!(err, SessionErrorKind::Telegram(TelegramErrorKind::BadRequest { ref message })
+                            if message.as_ref() == "Bad Request: STICKERSET_INVALID")
+                        {
+                            debug!(?set.short_name, "Source sticker set was deleted, stop following it..");
+
+                            if let Err(err) = set_follow_source_col(
+                                &mut uow,
+                                SetFollowSourceColByShortName::new(&set.short_name, false),
+                            )
+                            .await
+                            {
+                                error!(?err, ?set.short_name, "Failed to update `follow_source` column for sticker set: ");
+                                continue;
+                            }
+
+                            if let Err(err) = bot
+                                .send(
+                                    SendMessage::new(
+                                        set.tg_id,
+                                        format!(
+                                            "Sticker pack {source_link} was deleted, so {copy} doesn't follow it anymore.",
+                                            copy = html_text_link(
+                                                html_quote(&set.title),
+                                                format!("{TELEGRAM_STICKER_SET_URL}{}", set.short_name)
+                                            )
+                                        ),
+                                    )
+                                    .parse_mode(ParseMode::HTML),
+                                )
+                                .await
+                            {
+                                error!(?err, ?set.tg_id, "Failed to notify user: ");
+                            }
+                        } else {
+                            error!(
+                                ?err,
+                                source_short_name, "Failed to get source sticker set: "
+                            );
+                        }
+
+                        continue;
+                    }
+                };
+
+                let source_sticker_ids: Vec<String> = source
+                    .stickers
+                    .iter()
+                    .map(|sticker| sticker.file_unique_id.to_string())
+                    .collect();
+
+                // stickers of the source, that were there when the set started following it,
+                // aren't added, so deselected and removed stickers don't come back
+                let known_sticker_ids = match set.source_sticker_ids {
+                    Some(ref known_sticker_ids) => known_sticker_ids,
+                    None => {
+                        if let Err(err) = update_source_sticker_ids(
+                            &mut uow,
+                            UpdateSourceStickerIds::new(&set.short_name, &source_sticker_ids),
+                        )
+                        .await
+                        {
+                            error!(?err, ?set.short_name, "Failed to update source sticker ids of sticker set: ");
+                        }
+                        continue;
+                    }
+                };
+
+                let copy = match bot.send(GetStickerSet::new(set.short_name.as_str())).await {
+                    Ok(copy) => copy,
+                    // `deleted_sets_upd` takes care of deleted sticker sets
+                    Err(err) => {
+                        error!(?err, ?set.short_name, "Failed to get sticker set: ");
+                        continue;
+                    }
+                };
+
+                let appeared_stickers = source
+                    .stickers
+                    .into_iter()
+                    .filter(|sticker| {
+                        !known_sticker_ids
+                            .iter()
+                            .any(|id| *id == *sticker.file_unique_id)
+                    })
+                    .collect();
+                let mut new_stickers = missing_stickers(appeared_stickers, &copy.stickers);
+
+                if !new_stickers.is_empty() {
+                    let new_stickers_len = new_stickers.len();
+                    let remaining = max_sticker_set_length(&copy.sticker_type)
+                        .saturating_sub(copy.stickers.len());
+                    new_stickers.truncate(remaining);
+
+                    let copy_link = html_text_link(
+                        html_quote(&copy.title),
+                        format!("{TELEGRAM_STICKER_SET_URL}{}", set.short_name),
+                    );
+
+                    let mut text = format!(
+                        "{new_len} new sticker(s) appeared in {source_link}. ",
+                        new_len = html_code(new_stickers_len.to_string())
+                    );
+                    if new_stickers.is_empty() {
+                        text.push_str(&format!(
+                            "{copy_link} is completely filled, so I can't add them."
+                        ));
+                    } else {
+                        text.push_str(&format!("Adding them into {copy_link}.."));
+                        if new_stickers.len() < new_stickers_len {
+                            text.push_str(&format!(
+                                " Only {added_len} of them fit into your sticker pack.",
+                                added_len = html_code(new_stickers.len().to_string())
+                            ));
+                        }
+                    }
+
+                    if let Err(err) = bot
+                        .send(SendMessage::new(set.tg_id, text).parse_mode(ParseMode::HTML))
+                        .await
+                    {
+                        error!(?err, ?set.tg_id, "Failed to notify user: ");
+                    }
+
+                    if !new_stickers.is_empty()
+                        && let Err(err) = job_queue
+                            .enqueue_add_stickers(
+                                &uow_factory,
+                                AddStickers {
+                                    tg_id: set.tg_id,
+                                    short_name: set.short_name.clone(),
+                                    title: copy.title.to_string(),
+                                    sticker_type: copy.sticker_type.to_string(),
+                                    stickers: new_stickers,
+                                },
+                            )
+                            .await
+                    {
+                        error!(?err, ?set.short_name, "Failed to enqueue new stickers of the source set: ");
+                        // the stickers will be added at the next check
+                        continue;
+                    }
+                }
+
+                if *known_sticker_ids != source_sticker_ids
+                    && let Err(err) = update_source_sticker_ids(
+                        &mut uow,
+                        UpdateSourceStickerIds::new(&set.short_name, &source_sticker_ids),
+                    )
+                    .await
+                {
+                    error!(?err, ?set.short_name, "Failed to update source sticker ids of sticker set: ");
+                }
+            }
+
+            last_upd_time = Utc::now();
+            debug!(
+                "Finish checking for new stickers in source sets. Current time: `{:?}`",
+                last_upd_time
+            );
+        }
+    });
+
+    Ok(())
+}
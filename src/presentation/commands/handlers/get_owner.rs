@@ -1,6 +1,6 @@
 use grammers_client::Client;
 use telers::{
-    Bot, Extension,
+    Extension,
     enums::ParseMode,
     event::{EventReturn, telegram::HandlerResult},
     fsm::{Context, Storage},
@@ -11,52 +11,86 @@ use telers::{
 use tracing::error;
 
 use crate::presentation::{
-    commands::{common::send_default_error_message, states::get_owner::GetOwnerState},
+    commands::{
+        common::{replied_sticker, send_default_error_message},
+        states::get_owner::GetOwnerState,
+    },
+    scheduler::Scheduler,
     telegram_application::get_sticker_set_user_id,
 };
 
 pub async fn get_owner_handler<S: Storage>(
-    bot: Bot,
+    Extension(bot): Extension<Scheduler>,
     message: MessageText,
     fsm: Context<S>,
+    Extension(client): Extension<Client>,
 ) -> HandlerResult {
     fsm.finish().await.map_err(Into::into)?;
 
+    fsm.set_state(GetOwnerState::GetStickers)
+        .await
+        .map_err(Into::into)?;
+
+    if let Some((reply_id, sticker)) = replied_sticker(&message) {
+        return send_owner_id(
+            &bot,
+            &client,
+            message.chat.id(),
+            reply_id,
+            sticker.set_name.as_deref(),
+        )
+        .await;
+    }
+
     bot.send(SendMessage::new(
         message.chat.id(),
         "Send me a sticker and i'll show you the owner of this sticker pack:",
     ))
     .await?;
 
-    fsm.set_state(GetOwnerState::GetStickers)
-        .await
-        .map_err(Into::into)?;
-
     Ok(EventReturn::Finish)
 }
 
 pub async fn get_owner_id(
-    bot: Bot,
+    Extension(bot): Extension<Scheduler>,
     message: MessageSticker,
     Extension(client): Extension<Client>,
 ) -> HandlerResult {
-    let set_name = match message.sticker.set_name {
+    send_owner_id(
+        &bot,
+        &client,
+        message.chat.id(),
+        message.id,
+        message.sticker.set_name.as_deref(),
+    )
+    .await
+}
+
+/// Send the id of the owner of sticker set `set_name` as a reply to the message with the sticker
+async fn send_owner_id(
+    bot: &Scheduler,
+    client: &Client,
+    chat_id: i64,
+    message_id: i64,
+    set_name: Option<&str>,
+) -> HandlerResult {
+    let set_name = match set_name {
         Some(set_name) => set_name,
         None => {
             bot.send(
         SendMessage::new(
-            message.chat.id(),
+            chat_id,
                 "Unable to retrieve the owner's ID because this sticker is not in any sticker pack. Try to send another sticker or use /cancel instead."
             )
             .parse_mode(ParseMode::HTML)
-            .reply_parameters(ReplyParameters::new(message.id).chat_id(message.chat.id())))
+            .reply_parameters(ReplyParameters::new(message_id).chat_id(chat_id)))
             .await?;
 
             return Ok(EventReturn::Finish);
         }
     };
 
-    let owner_id = match get_sticker_set_user_id(&set_name, &client).await {
+    let owner_id = match get_sticker_set_user_id(set_name, client).await {
         Ok(id) => id,
         Err(error) => {
             error!(
@@ -65,7 +99,7 @@ pub async fn get_owner_id(
                 "Error occurred while getting sticker set user id: "
             );
 
-            send_default_error_message(&bot, message.chat.id()).await?;
+            send_default_error_message(bot, chat_id).await?;
 
             return Ok(EventReturn::Finish);
         }
@@ -73,14 +107,14 @@ pub async fn get_owner_id(
 
     bot.send(
         SendMessage::new(
-            message.chat.id(),
+            chat_id,
             format!(
                 "The ID of the owner of this sticker pack — {}. Send the next one or use /cancel instead.",
                 html_code(owner_id.to_string())
             ),
         )
         .parse_mode(ParseMode::HTML)
-        .reply_parameters(ReplyParameters::new(message.id).chat_id(message.chat.id())),
+        .reply_parameters(ReplyParameters::new(message_id).chat_id(chat_id)),
     )
     .await?;
 
@@ -0,0 +1,356 @@
+use telers::{
+    Extension,
+    enums::ParseMode,
+    errors::{TelegramErrorKind, session::ErrorKind},
+    event::{EventReturn, telegram::HandlerResult},
+    fsm::{Context, Storage},
+    methods::{GetMe, GetStickerSet, SendMessage},
+    types::{MessageSticker, MessageText, ReplyParameters},
+    utils::text::{html_code, html_quote},
+};
+use tracing::error;
+
+use crate::{
+    application::common::traits::uow::UoWFactory as UoWFactoryTrait,
+    core::helpers::{
+        common::{max_sticker_set_length, merge_unique, stolen_sticker_type},
+        constants::CREATE_SET_IN_ONE_GO_LENGTH_LIMIT,
+    },
+    domain::entities::sticker::StickerKind,
+    presentation::{
+        commands::{
+            common::{check_set_title, send_default_error_message},
+            states::merge_sticker_sets::MergeStickerSetsState,
+        },
+        jobs::{JobQueue, StealStickerSet},
+        scheduler::Scheduler,
+    },
+};
+
+pub async fn merge_handler<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+) -> HandlerResult {
+    fsm.finish().await.map_err(Into::into)?;
+
+    fsm.set_state(MergeStickerSetsState::GetStickerSetsToMerge)
+        .await
+        .map_err(Into::into)?;
+
+    bot.send(SendMessage::new(
+        message.chat.id(),
+        "Send me one sticker from each sticker pack you want to merge. \
+        The stickers will be in the same order as the sticker packs you send. \
+        When you're ready, use /done.",
+    ))
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
+
+pub async fn get_sticker_set_to_merge<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageSticker,
+    fsm: Context<S>,
+) -> HandlerResult {
+    let set_name = match message.sticker.set_name {
+        Some(set_name) => set_name,
+        None => {
+            bot.send(SendMessage::new(
+                message.chat.id(),
+                "This sticker is without sticker pack. Try to send another sticker pack.",
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    let mut set_names: Vec<Box<str>> = fsm
+        .get_value("merge_sticker_set_names")
+        .await
+        .map_err(Into::into)?
+        .unwrap_or_default();
+
+    if set_names.contains(&set_name) {
+        bot.send(
+            SendMessage::new(
+                message.chat.id(),
+                "This sticker pack has already been added. Send a sticker from another pack or use /done.",
+            )
+            .reply_parameters(ReplyParameters::new(message.id).chat_id(message.chat.id())),
+        )
+        .await?;
+
+        return Ok(EventReturn::Finish);
+    }
+
+    let sticker_set = match bot.send(GetStickerSet::new(&*set_name)).await {
+        Ok(sticker_set) => sticker_set,
+        Err(error) => {
+            if matches!(&error, ErrorKind::Telegram(TelegramErrorKind::BadRequest { message }) if **message == *"Bad Request: STICKERSET_INVALID")
+            {
+                bot.send(SendMessage::new(
+                    message.chat.id(),
+                    "This sticker is without sticker pack. Try to send another sticker pack.",
+                ))
+                .await?;
+
+                return Ok(EventReturn::Finish);
+            }
+
+            error!(
+                ?error,
+                "Error occurred while getting sticker set to merge: "
+            );
+
+            send_default_error_message(&bot, message.chat.id()).await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    let sticker_kind = StickerKind::from(stolen_sticker_type(&sticker_set.sticker_type));
+
+    // all merged sticker sets should be compatible with the first one, it defines the type of the merged set
+    match fsm
+        .get_value::<_, Box<str>>("merge_sticker_type")
+        .await
+        .map_err(Into::into)?
+    {
+        Some(merge_sticker_type)
+            if !StickerKind::from(&*merge_sticker_type).accepts(sticker_kind) =>
+        {
+            bot.send(
+                SendMessage::new(
+                    message.chat.id(),
+                    format!(
+                        "You can't merge {merge_kind} with {kind}. Send a sticker from another pack or use /done.",
+                        merge_kind = StickerKind::from(&*merge_sticker_type).description(),
+                        kind = sticker_kind.description(),
+                    ),
+                )
+                .reply_parameters(ReplyParameters::new(message.id).chat_id(message.chat.id())),
+            )
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+        Some(_) => (),
+        None => {
+            fsm.set_value("merge_sticker_type", sticker_kind.as_str())
+                .await
+                .map_err(Into::into)?;
+        }
+    }
+
+    set_names.push(set_name);
+    let set_names_len = set_names.len();
+
+    fsm.set_value("merge_sticker_set_names", set_names)
+        .await
+        .map_err(Into::into)?;
+
+    bot.send(
+        SendMessage::new(
+            message.chat.id(),
+            format!(
+                "Sticker pack {title} ({stickers_len} stickers) was added, {set_names_len} packs to merge in total. \
+                Send a sticker from the next pack or use /done.",
+                title = html_code(html_quote(&sticker_set.title)),
+                stickers_len = sticker_set.stickers.len()
+            ),
+        )
+        .parse_mode(ParseMode::HTML)
+        .reply_parameters(ReplyParameters::new(message.id).chat_id(message.chat.id())),
+    )
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
+
+pub async fn merge_done<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+) -> HandlerResult {
+    let set_names_len = fsm
+        .get_value::<_, Vec<Box<str>>>("merge_sticker_set_names")
+        .await
+        .map_err(Into::into)?
+        .map(|set_names| set_names.len())
+        .unwrap_or_default();
+
+    if set_names_len < 2 {
+        bot.send(SendMessage::new(
+            message.chat.id(),
+            "Send me stickers from at least two sticker packs and only then use /done command.",
+        ))
+        .await?;
+
+        return Ok(EventReturn::Finish);
+    }
+
+    fsm.set_state(MergeStickerSetsState::CreateMergedStickerSet)
+        .await
+        .map_err(Into::into)?;
+
+    bot.send(SendMessage::new(
+        message.chat.id(),
+        "Now enter name for your new sticker pack (1-64 characters):",
+    ))
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
+
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
+pub async fn create_merged_sticker_set<S, UoWFactory>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+    Extension(job_queue): Extension<JobQueue>,
+    Extension(uow_factory): Extension<UoWFactory>,
+) -> HandlerResult
+where
+    S: Storage,
+    UoWFactory: UoWFactoryTrait,
+{
+    if !check_set_title(&bot, message.chat.id(), &message.text).await? {
+        return Ok(EventReturn::Finish);
+    }
+    let new_set_title = message.text;
+
+    let set_names: Vec<Box<str>> = fsm
+        .get_value("merge_sticker_set_names")
+        .await
+        .map_err(Into::into)?
+        // only panic if i'm forget call fsm.set_value() in function get_sticker_set_to_merge()
+        .expect("Sticker set names to merge should be set");
+    let sticker_type: Box<str> = fsm
+        .get_value("merge_sticker_type")
+        .await
+        .map_err(Into::into)?
+        .expect("Sticker type of sticker sets to merge should be set");
+
+    fsm.finish().await.map_err(Into::into)?;
+
+    let mut sticker_lists = Vec::with_capacity(set_names.len());
+    // the merged sticker set gets the thumbnail of the first one
+    let mut thumbnail = None;
+    let mut titles = Vec::with_capacity(set_names.len());
+    for set_name in set_names.iter() {
+        match bot.send(GetStickerSet::new(set_name.as_ref())).await {
+            Ok(sticker_set) => {
+                if sticker_lists.is_empty() {
+                    thumbnail = sticker_set.thumbnail;
+                }
+                titles.push(sticker_set.title);
+                sticker_lists.push(sticker_set.stickers);
+            }
+            Err(error) => {
+                error!(
+                    ?error,
+                    ?set_name,
+                    "Error occurred while getting sticker set to merge: "
+                );
+
+                send_default_error_message(&bot, message.chat.id()).await?;
+
+                return Ok(EventReturn::Finish);
+            }
+        }
+    }
+
+    // stickers that don't fit into one sticker set go to the next parts of the merged one
+    let merged = merge_unique(sticker_lists, |sticker| sticker.file_unique_id.clone());
+
+    let max_set_length = max_sticker_set_length(&sticker_type);
+    let parts_len = merged.items.len().div_ceil(max_set_length);
+
+    if merged.duplicates > 0 {
+        bot.send(
+            SendMessage::new(
+                message.chat.id(),
+                format!(
+                    "Skipped duplicate stickers: {duplicates}.",
+                    duplicates = html_code(merged.duplicates.to_string()),
+                ),
+            )
+            .parse_mode(ParseMode::HTML),
+        )
+        .await?;
+    }
+
+    if parts_len > 1 {
+        bot.send(
+            SendMessage::new(
+                message.chat.id(),
+                format!(
+                    "There are {stickers_len} stickers, but a sticker pack can contain only {max_len}, \
+                    so the merged sticker pack will be split into {parts_len} sticker packs.",
+                    stickers_len = html_code(merged.items.len().to_string()),
+                    max_len = html_code(max_set_length.to_string()),
+                    parts_len = html_code(parts_len.to_string())
+                ),
+            )
+            .parse_mode(ParseMode::HTML),
+        )
+        .await?;
+    }
+
+    // only panic if bot using in channels, but i'm using private filter in launch function
+    let user_id = message.from.expect("user without id").id;
+
+    // cant panic because bot cant be without username
+    let bot_username = bot
+        .send(GetMe::new())
+        .await?
+        .username
+        .expect("bot without username :/");
+
+    let job = StealStickerSet {
+        tg_id: user_id,
+        source_short_name: set_names.join(", "),
+        source_title: titles.join(", "),
+        short_name: None,
+        thumbnail,
+        title: new_set_title.to_string(),
+        sticker_type: sticker_type.to_string(),
+        stickers: merged.items,
+    };
+
+    let position = match job_queue
+        .try_enqueue_steal_sticker_set(&uow_factory, &bot_username, job)
+        .await
+    {
+        Ok(position) => position,
+        Err(err) => {
+            error!(?err, "Error occurred while enqueueing merge job: ");
+
+            bot.send(SendMessage::new(
+                message.chat.id(),
+                "Sorry, I'm too busy right now. Please try again in a few minutes.",
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    bot.send(SendMessage::new(
+        message.chat.id(),
+        format!(
+            "Merging sticker packs into the new sticker pack with name {title} for you.. Your place in the queue: {position}. \
+            It may take up to a several minutes, if there are more than {CREATE_SET_IN_ONE_GO_LENGTH_LIMIT} stickers. \
+            I'll send you a message when it's ready.",
+            title = html_code(html_quote(&new_set_title)),
+            position = html_code(position.to_string())
+        ),
+    ).parse_mode(ParseMode::HTML))
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use telers::{
-    Bot, Extension,
+    Extension,
     enums::ParseMode,
     errors::{HandlerError, TelegramErrorKind, session::ErrorKind},
     event::{EventReturn, telegram::HandlerResult},
@@ -22,7 +22,7 @@ use crate::{
         helpers::constants::STICKER_SETS_NUMBER_PER_PAGE, helpers::texts::current_page_message,
     },
     domain::entities::set::Set,
-    presentation::commands::states::callback_data::CallbackDataPrefix,
+    presentation::{commands::states::callback_data::CallbackDataPrefix, scheduler::Scheduler},
 };
 
 impl From<BeginError> for HandlerError {
@@ -45,7 +45,7 @@ impl GetButtonsError {
 }
 
 pub async fn my_stickers_handler<S, UoWFactory>(
-    bot: Bot,
+    Extension(bot): Extension<Scheduler>,
     message: MessageText,
     fsm: FSMContext<S>,
     Extension(uow_factory): Extension<UoWFactory>,
@@ -103,7 +103,7 @@ where
 }
 
 pub async fn process_buttons<UoWFactory>(
-    bot: Bot,
+    Extension(bot): Extension<Scheduler>,
     callback_query: CallbackQuery,
     Extension(uow_factory): Extension<UoWFactory>,
 ) -> HandlerResult
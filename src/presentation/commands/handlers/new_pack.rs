@@ -0,0 +1,259 @@
+use telers::{
+    Extension,
+    enums::ParseMode,
+    errors::HandlerError,
+    event::{EventReturn, telegram::HandlerResult},
+    fsm::{Context, Storage},
+    methods::{CreateNewStickerSet, GetMe, SendMessage},
+    types::{Message, MessageText, ReplyParameters, Sticker},
+    utils::text::html_code,
+};
+use tracing::error;
+
+use crate::{
+    application::{
+        common::traits::uow::UoWFactory as UoWFactoryTrait, interactors::create_set::create_set,
+        set::dto::create::Create as CreateSet,
+    },
+    core::helpers::{
+        common::{generate_sticker_set_name_and_link, input_sticker},
+        constants::{CREATE_SET_IN_ONE_GO_LENGTH_LIMIT, REGULAR_STICKER_TYPE},
+        texts::sticker_set_message,
+    },
+    presentation::{
+        chat_locks::ChatLocks,
+        commands::{
+            common::{check_set_title, send_default_error_message, upload_sticker_image},
+            states::new_sticker_set::NewStickerSetState,
+        },
+        scheduler::Scheduler,
+    },
+};
+
+pub async fn new_pack_handler<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+) -> HandlerResult {
+    fsm.finish().await.map_err(Into::into)?;
+
+    fsm.set_state(NewStickerSetState::GetImages)
+        .await
+        .map_err(Into::into)?;
+
+    bot.send(
+        SendMessage::new(
+            message.chat.id(),
+            format!(
+                "Send me photos or PNG, WebP and JPEG images (up to {max_len}) to make a new sticker pack from them. \
+                The emoji from the caption will be used for the sticker. When you're ready, use /done.",
+                max_len = html_code(CREATE_SET_IN_ONE_GO_LENGTH_LIMIT.to_string())
+            ),
+        )
+        .parse_mode(ParseMode::HTML),
+    )
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
+
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
+pub async fn get_images_for_new_pack<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    Extension(chat_locks): Extension<ChatLocks>,
+    message: Message,
+    fsm: Context<S>,
+) -> HandlerResult {
+    let chat_id = message.chat().id();
+
+    let stickers: Vec<Sticker> = fsm
+        .get_value("new_pack_stickers")
+        .await
+        .map_err(Into::into)?
+        .unwrap_or_default();
+
+    if stickers.len() >= CREATE_SET_IN_ONE_GO_LENGTH_LIMIT {
+        return send_stickers_limit_message(&bot, chat_id).await;
+    }
+
+    // only panic if messages uses in channels, but i'm using private filter
+    let user_id = message.from().expect("user not specified").id;
+
+    let sticker = match upload_sticker_image(&bot, &message, user_id).await? {
+        Some(sticker) => sticker,
+        // user is already notified about the error
+        None => return Ok(EventReturn::Finish),
+    };
+
+    // the images of an album are handled at the same time, so the list is read and written under the lock
+    // of the chat after the upload, otherwise the stickers added meanwhile are lost
+    let _chat_lock = chat_locks.lock(chat_id).await;
+    let mut stickers: Vec<Sticker> = fsm
+        .get_value("new_pack_stickers")
+        .await
+        .map_err(Into::into)?
+        .unwrap_or_default();
+
+    if stickers.len() >= CREATE_SET_IN_ONE_GO_LENGTH_LIMIT {
+        return send_stickers_limit_message(&bot, chat_id).await;
+    }
+
+    stickers.push(sticker);
+
+    fsm.set_value("new_pack_stickers", stickers)
+        .await
+        .map_err(Into::into)?;
+
+    bot.send(
+        SendMessage::new(
+            chat_id,
+            "Sticker made from the image! Send the next one or use /done.",
+        )
+        .reply_parameters(ReplyParameters::new(message.id()).chat_id(chat_id)),
+    )
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
+
+/// Tell the user that the new sticker pack can't have more stickers
+async fn send_stickers_limit_message(bot: &Scheduler, chat_id: i64) -> HandlerResult {
+    bot.send(
+        SendMessage::new(
+            chat_id,
+            format!(
+                "The amount of stickers has reached {max_len}. Use /done to create the sticker pack, \
+                you can add more stickers to it later using /addstickers.",
+                max_len = html_code(CREATE_SET_IN_ONE_GO_LENGTH_LIMIT.to_string())
+            ),
+        )
+        .parse_mode(ParseMode::HTML),
+    )
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
+
+pub async fn new_pack_done<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+) -> HandlerResult {
+    let stickers: Vec<Sticker> = fsm
+        .get_value("new_pack_stickers")
+        .await
+        .map_err(Into::into)?
+        .unwrap_or_default();
+
+    if stickers.is_empty() {
+        bot.send(SendMessage::new(
+            message.chat.id(),
+            "You haven't sent a single image! Send the images and only then use the /done command.",
+        ))
+        .await?;
+
+        return Ok(EventReturn::Finish);
+    }
+
+    fsm.set_state(NewStickerSetState::GetTitle)
+        .await
+        .map_err(Into::into)?;
+
+    bot.send(SendMessage::new(
+        message.chat.id(),
+        "Now enter a name for your new sticker pack (1-64 characters):",
+    ))
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
+
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
+pub async fn create_new_pack<S, UoWFactory>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+    Extension(uow_factory): Extension<UoWFactory>,
+) -> HandlerResult
+where
+    S: Storage,
+    UoWFactory: UoWFactoryTrait,
+{
+    if !check_set_title(&bot, message.chat.id(), &message.text).await? {
+        return Ok(EventReturn::Finish);
+    }
+    let title = message.text;
+
+    let stickers: Vec<Sticker> = fsm
+        .get_value("new_pack_stickers")
+        .await
+        .map_err(Into::into)?
+        // only panic if i'm forget call fsm.set_value() in function get_images_for_new_pack()
+        .expect("Stickers for new sticker set should be set");
+
+    fsm.finish().await.map_err(Into::into)?;
+
+    // only panic if messages uses in channels, but i'm using private filter
+    let user_id = message.from.expect("user not specified").id;
+
+    // cant panic because bot cant be without username
+    let bot_username = bot
+        .send(GetMe::new())
+        .await?
+        .username
+        .expect("bot without username :/");
+
+    let (set_name, set_link) = generate_sticker_set_name_and_link(11, &bot_username);
+
+    if let Err(error) = bot
+        .send(CreateNewStickerSet::new(
+            user_id,
+            set_name.as_str(),
+            title.as_ref(),
+            stickers
+                .iter()
+                .map(|sticker| input_sticker(sticker, REGULAR_STICKER_TYPE)),
+        ))
+        .await
+    {
+        error!(
+            ?error,
+            ?set_name,
+            "Error occurred while creating new sticker set from images: "
+        );
+
+        send_default_error_message(&bot, message.chat.id()).await?;
+
+        return Ok(EventReturn::Finish);
+    }
+
+    let mut uow = uow_factory.create_uow();
+
+    // the stickers are made from images, so the sticker set has no source
+    create_set(
+        &mut uow,
+        CreateSet::new(
+            user_id,
+            &set_name,
+            &title,
+            None,
+            None,
+            stickers.len() as i32,
+        ),
+    )
+    .await
+    .map_err(HandlerError::new)?;
+
+    bot.send(
+        SendMessage::new(
+            message.chat.id(),
+            sticker_set_message(&title, &set_name, &set_link, None),
+        )
+        .parse_mode(ParseMode::HTML),
+    )
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
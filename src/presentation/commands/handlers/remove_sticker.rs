@@ -0,0 +1,363 @@
+use grammers_client::Client;
+use telers::{
+    Extension,
+    enums::ParseMode,
+    errors::{HandlerError, TelegramErrorKind, session::ErrorKind},
+    event::{EventReturn, telegram::HandlerResult},
+    fsm::{Context, Storage},
+    methods::{
+        AddStickerToSet, DeleteStickerFromSet, GetStickerSet, SendMessage, SetStickerPositionInSet,
+    },
+    types::{MessageSticker, MessageText, ReplyParameters, Sticker},
+    utils::text::{html_quote, html_text_link},
+};
+use tracing::error;
+
+use crate::{
+    application::{
+        common::traits::uow::UoWFactory as UoWFactoryTrait,
+        interactors::update_sticker_count::update_sticker_count,
+        set::dto::update_sticker_count::UpdateStickerCount,
+    },
+    core::helpers::{common::input_sticker, constants::TELEGRAM_STICKER_SET_URL},
+    presentation::{
+        commands::{
+            common::{check_set_owner, replied_sticker, send_default_error_message},
+            states::remove_sticker::RemoveStickerState,
+        },
+        scheduler::Scheduler,
+    },
+};
+
+/// Sticker removed in the current session: `(set name, set type, position in the set, sticker)`.
+/// It's enough to put the sticker back on its place.
+type RemovedSticker = (Box<str>, Box<str>, usize, Sticker);
+
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
+pub async fn remove_sticker_handler<S, UoWFactory>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+    Extension(client): Extension<Client>,
+    Extension(uow_factory): Extension<UoWFactory>,
+) -> HandlerResult
+where
+    S: Storage,
+    UoWFactory: UoWFactoryTrait,
+{
+    fsm.finish().await.map_err(Into::into)?;
+
+    fsm.set_state(RemoveStickerState::GetStickersToRemove)
+        .await
+        .map_err(Into::into)?;
+
+    if let Some((reply_id, sticker)) = replied_sticker(&message) {
+        return process_sticker_to_remove(
+            &bot,
+            &fsm,
+            &client,
+            &uow_factory,
+            message.chat.id(),
+            reply_id,
+            // only panic if messages uses in channels, but i'm using private filter
+            message.from.expect("user not specified").id,
+            sticker,
+        )
+        .await;
+    }
+
+    bot.send(SendMessage::new(
+        message.chat.id(),
+        "Send me the stickers from your stolen sticker pack that you want to remove. \
+        You can put the last removed sticker back using /undo. When you're ready, use /done.",
+    ))
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
+
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
+pub async fn get_sticker_to_remove<S, UoWFactory>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageSticker,
+    fsm: Context<S>,
+    Extension(client): Extension<Client>,
+    Extension(uow_factory): Extension<UoWFactory>,
+) -> HandlerResult
+where
+    S: Storage,
+    UoWFactory: UoWFactoryTrait,
+{
+    process_sticker_to_remove(
+        &bot,
+        &fsm,
+        &client,
+        &uow_factory,
+        message.chat.id(),
+        message.id,
+        // only panic if messages uses in channels, but i'm using private filter
+        message.from.expect("user not specified").id,
+        &message.sticker,
+    )
+    .await
+}
+
+/// Check that the sticker set of the `sticker` was stolen by the bot for the user `user_id`
+/// and remove the `sticker` from it, remembering it for /undo
+async fn process_sticker_to_remove<S, UoWFactory>(
+    bot: &Scheduler,
+    fsm: &Context<S>,
+    client: &Client,
+    uow_factory: &UoWFactory,
+    chat_id: i64,
+    message_id: i64,
+    user_id: i64,
+    sticker: &Sticker,
+) -> HandlerResult
+where
+    S: Storage,
+    UoWFactory: UoWFactoryTrait,
+{
+    let set_name = match sticker.set_name.as_deref() {
+        Some(set_name) => set_name,
+        None => {
+            bot.send(SendMessage::new(
+                chat_id,
+                "This sticker is without sticker pack. Try to send another sticker.",
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    let mut owned_sets: Vec<Box<str>> = fsm
+        .get_value("remove_sticker_owned_sets")
+        .await
+        .map_err(Into::into)?
+        .unwrap_or_default();
+
+    // the owner of the sticker set doesn't change, so we check it only once per session
+    if !owned_sets
+        .iter()
+        .any(|owned_set| owned_set.as_ref() == set_name)
+    {
+        if !check_set_owner(bot, client, chat_id, user_id, set_name).await? {
+            return Ok(EventReturn::Finish);
+        }
+
+        owned_sets.push(set_name.into());
+        fsm.set_value("remove_sticker_owned_sets", owned_sets)
+            .await
+            .map_err(Into::into)?;
+    }
+
+    let sticker_set = bot.send(GetStickerSet::new(set_name)).await?;
+
+    let position = match sticker_set
+        .stickers
+        .iter()
+        .position(|set_sticker| set_sticker.file_unique_id == sticker.file_unique_id)
+    {
+        Some(position) => position,
+        None => {
+            bot.send(
+                SendMessage::new(
+                    chat_id,
+                    "This sticker was already removed from the sticker pack.",
+                )
+                .reply_parameters(ReplyParameters::new(message_id).chat_id(chat_id)),
+            )
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    if let Err(error) = bot
+        .send(DeleteStickerFromSet::new(sticker.file_id.as_ref()))
+        .await
+    {
+        error!(
+            ?error,
+            ?set_name,
+            "Error occurred while removing sticker from sticker set: "
+        );
+
+        send_default_error_message(bot, chat_id).await?;
+
+        return Ok(EventReturn::Finish);
+    }
+
+    let mut uow = uow_factory.create_uow();
+
+    update_sticker_count(&mut uow, UpdateStickerCount::new(set_name, -1))
+        .await
+        .map_err(HandlerError::new)?;
+
+    let mut removed_stickers: Vec<RemovedSticker> = fsm
+        .get_value("removed_stickers")
+        .await
+        .map_err(Into::into)?
+        .unwrap_or_default();
+
+    removed_stickers.push((
+        set_name.into(),
+        sticker_set.sticker_type.to_string().into(),
+        position,
+        // the stickers of the sticker set have the information, that Telegram doesn't send with the message
+        sticker_set.stickers[position].clone(),
+    ));
+
+    fsm.set_value("removed_stickers", removed_stickers)
+        .await
+        .map_err(Into::into)?;
+
+    bot.send(
+        SendMessage::new(
+            chat_id,
+            format!(
+                "This sticker was removed from {link}. Send the next one, use /undo to put it back or /done.",
+                link = html_text_link(
+                    html_quote(&sticker_set.title),
+                    format!("{TELEGRAM_STICKER_SET_URL}{set_name}")
+                )
+            ),
+        )
+        .parse_mode(ParseMode::HTML)
+        .reply_parameters(ReplyParameters::new(message_id).chat_id(chat_id)),
+    )
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
+
+/// Put the last removed sticker back on its place in the sticker set
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
+pub async fn undo_remove_sticker<S, UoWFactory>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+    Extension(uow_factory): Extension<UoWFactory>,
+) -> HandlerResult
+where
+    S: Storage,
+    UoWFactory: UoWFactoryTrait,
+{
+    let mut removed_stickers: Vec<RemovedSticker> = fsm
+        .get_value("removed_stickers")
+        .await
+        .map_err(Into::into)?
+        .unwrap_or_default();
+
+    let (set_name, set_type, position, sticker) = match removed_stickers.pop() {
+        Some(removed_sticker) => removed_sticker,
+        None => {
+            bot.send(SendMessage::new(
+                message.chat.id(),
+                "There is nothing to put back.",
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    // only panic if messages uses in channels, but i'm using private filter
+    let user_id = message.from.expect("user not specified").id;
+
+    if let Err(error) = bot
+        .send(AddStickerToSet::new(
+            user_id,
+            set_name.as_ref(),
+            input_sticker(&sticker, &set_type),
+        ))
+        .await
+    {
+        if matches!(&error, ErrorKind::Telegram(TelegramErrorKind::BadRequest { message }) if message.contains("STICKERS_TOO_MUCH"))
+        {
+            bot.send(SendMessage::new(
+                message.chat.id(),
+                "This sticker pack is completely filled, so I can't put the sticker back. \
+                Remove another sticker and try /undo again.",
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+
+        error!(
+            ?error,
+            ?set_name,
+            "Error occurred while putting sticker back into sticker set: "
+        );
+
+        send_default_error_message(&bot, message.chat.id()).await?;
+
+        return Ok(EventReturn::Finish);
+    }
+
+    fsm.set_value("removed_stickers", removed_stickers)
+        .await
+        .map_err(Into::into)?;
+
+    let mut uow = uow_factory.create_uow();
+
+    update_sticker_count(&mut uow, UpdateStickerCount::new(&set_name, 1))
+        .await
+        .map_err(HandlerError::new)?;
+
+    // the sticker is added to the end of the sticker set, so move it to the place it was removed from
+    let sticker_set = bot.send(GetStickerSet::new(set_name.as_ref())).await?;
+    if let Some(added_sticker) = sticker_set.stickers.last()
+        && position + 1 < sticker_set.stickers.len()
+        && let Err(error) = bot
+            .send(SetStickerPositionInSet::new(
+                added_sticker.file_id.as_ref(),
+                position as i64,
+            ))
+            .await
+    {
+        error!(
+            ?error,
+            ?set_name,
+            "Error occurred while restoring sticker position: "
+        );
+    }
+
+    bot.send(
+        SendMessage::new(
+            message.chat.id(),
+            format!(
+                "The sticker was put back into {link}. You can use /undo again or /done.",
+                link = html_text_link(
+                    html_quote(&sticker_set.title),
+                    format!("{TELEGRAM_STICKER_SET_URL}{set_name}")
+                )
+            ),
+        )
+        .parse_mode(ParseMode::HTML),
+    )
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
+
+pub async fn remove_stickers_done<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+) -> HandlerResult {
+    fsm.finish().await.map_err(Into::into)?;
+
+    bot.send(SendMessage::new(
+        message.chat.id(),
+        "Done! The removed stickers can't be put back with /undo anymore.",
+    ))
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
@@ -0,0 +1,196 @@
+use grammers_client::Client;
+use telers::{
+    Extension,
+    enums::ParseMode,
+    errors::HandlerError,
+    event::{EventReturn, telegram::HandlerResult},
+    fsm::{Context, Storage},
+    methods::{SendMessage, SetStickerSetTitle},
+    types::{MessageSticker, MessageText, Sticker},
+    utils::text::{html_quote, html_text_link},
+};
+use tracing::error;
+
+use crate::{
+    application::{
+        common::traits::uow::UoWFactory as UoWFactoryTrait,
+        interactors::update_set_title::update_set_title, set::dto::update_title::UpdateTitle,
+    },
+    core::helpers::constants::TELEGRAM_STICKER_SET_URL,
+    presentation::{
+        commands::{
+            common::{
+                check_set_owner, check_set_title, replied_sticker, send_default_error_message,
+            },
+            states::rename_sticker_set::RenameStickerSetState,
+        },
+        scheduler::Scheduler,
+    },
+};
+
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
+pub async fn rename_handler<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+    Extension(client): Extension<Client>,
+) -> HandlerResult {
+    fsm.finish().await.map_err(Into::into)?;
+
+    fsm.set_state(RenameStickerSetState::GetStickerSet)
+        .await
+        .map_err(Into::into)?;
+
+    if let Some((_, sticker)) = replied_sticker(&message) {
+        return process_sticker_set_to_rename(
+            &bot,
+            &fsm,
+            &client,
+            message.chat.id(),
+            // only panic if messages uses in channels, but i'm using private filter
+            message.from.expect("user not specified").id,
+            sticker,
+        )
+        .await;
+    }
+
+    bot.send(SendMessage::new(
+        message.chat.id(),
+        "Send me a sticker from your stolen sticker pack that you want to rename:",
+    ))
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
+
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
+pub async fn get_sticker_set_to_rename<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageSticker,
+    fsm: Context<S>,
+    Extension(client): Extension<Client>,
+) -> HandlerResult {
+    process_sticker_set_to_rename(
+        &bot,
+        &fsm,
+        &client,
+        message.chat.id(),
+        // only panic if messages uses in channels, but i'm using private filter
+        message.from.expect("user not specified").id,
+        &message.sticker,
+    )
+    .await
+}
+
+/// Check that the sticker set of the `sticker` was stolen by the bot for the user `user_id`
+/// and ask the user for the new title
+async fn process_sticker_set_to_rename<S: Storage>(
+    bot: &Scheduler,
+    fsm: &Context<S>,
+    client: &Client,
+    chat_id: i64,
+    user_id: i64,
+    sticker: &Sticker,
+) -> HandlerResult {
+    let set_name = match sticker.set_name.as_deref() {
+        Some(set_name) => set_name,
+        None => {
+            bot.send(SendMessage::new(
+                chat_id,
+                "This sticker is without sticker pack. Try to send another sticker.",
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    if !check_set_owner(bot, client, chat_id, user_id, set_name).await? {
+        return Ok(EventReturn::Finish);
+    }
+
+    fsm.set_value("rename_sticker_set_name", set_name)
+        .await
+        .map_err(Into::into)?;
+
+    fsm.set_state(RenameStickerSetState::GetNewTitle)
+        .await
+        .map_err(Into::into)?;
+
+    bot.send(SendMessage::new(
+        chat_id,
+        "Now enter the new name for your sticker pack (1-64 characters):",
+    ))
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
+
+pub async fn rename_sticker_set<S, UoWFactory>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+    Extension(uow_factory): Extension<UoWFactory>,
+) -> HandlerResult
+where
+    S: Storage,
+    UoWFactory: UoWFactoryTrait,
+{
+    if !check_set_title(&bot, message.chat.id(), &message.text).await? {
+        return Ok(EventReturn::Finish);
+    }
+    let new_title = message.text;
+
+    let set_name: Box<str> = fsm
+        .get_value("rename_sticker_set_name")
+        .await
+        .map_err(Into::into)?
+        // only panic if i'm forget call fsm.set_value() in function process_sticker_set_to_rename()
+        .expect("Sticker set name to rename should be set");
+
+    fsm.finish().await.map_err(Into::into)?;
+
+    if let Err(error) = bot
+        .send(SetStickerSetTitle::new(
+            set_name.as_ref(),
+            new_title.as_ref(),
+        ))
+        .await
+    {
+        error!(
+            ?error,
+            ?set_name,
+            "Error occurred while renaming sticker set: "
+        );
+
+        send_default_error_message(&bot, message.chat.id()).await?;
+
+        return Ok(EventReturn::Finish);
+    }
+
+    let mut uow = uow_factory.create_uow();
+
+    // sticker sets stolen before we started to save them are not in the database, that's fine
+    update_set_title(&mut uow, UpdateTitle::new(&set_name, &new_title))
+        .await
+        .map_err(HandlerError::new)?;
+
+    bot.send(
+        SendMessage::new(
+            message.chat.id(),
+            format!(
+                "Done! Your sticker pack is now called {link}.",
+                link = html_text_link(
+                    html_quote(new_title.as_ref()),
+                    format!("{TELEGRAM_STICKER_SET_URL}{set_name}")
+                )
+            ),
+        )
+        .parse_mode(ParseMode::HTML),
+    )
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
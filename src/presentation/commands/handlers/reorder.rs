@@ -0,0 +1,275 @@
+use grammers_client::Client;
+use telers::{
+    Extension,
+    enums::ParseMode,
+    event::{EventReturn, telegram::HandlerResult},
+    fsm::{Context, Storage},
+    methods::{AnswerCallbackQuery, GetStickerSet, SendMessage, SetStickerPositionInSet},
+    types::{
+        CallbackQuery, InlineKeyboardButton, InlineKeyboardMarkup, MessageSticker, MessageText,
+        ReplyParameters, Sticker,
+    },
+    utils::text::html_code,
+};
+use tracing::error;
+
+use crate::presentation::{
+    commands::{
+        common::{check_set_owner, replied_sticker, send_default_error_message},
+        states::{callback_data::CallbackDataPrefix, reorder_stickers::ReorderStickersState},
+    },
+    scheduler::Scheduler,
+};
+
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
+pub async fn reorder_handler<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+    Extension(client): Extension<Client>,
+) -> HandlerResult {
+    fsm.finish().await.map_err(Into::into)?;
+
+    fsm.set_state(ReorderStickersState::GetSticker)
+        .await
+        .map_err(Into::into)?;
+
+    if let Some((reply_id, sticker)) = replied_sticker(&message) {
+        return process_sticker_to_reorder(
+            &bot,
+            &fsm,
+            &client,
+            message.chat.id(),
+            reply_id,
+            // only panic if messages uses in channels, but i'm using private filter
+            message.from.expect("user not specified").id,
+            sticker,
+        )
+        .await;
+    }
+
+    bot.send(SendMessage::new(
+        message.chat.id(),
+        "Send me a sticker from your stolen sticker pack that you want to move:",
+    ))
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
+
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
+pub async fn get_sticker_to_reorder<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageSticker,
+    fsm: Context<S>,
+    Extension(client): Extension<Client>,
+) -> HandlerResult {
+    process_sticker_to_reorder(
+        &bot,
+        &fsm,
+        &client,
+        message.chat.id(),
+        message.id,
+        // only panic if messages uses in channels, but i'm using private filter
+        message.from.expect("user not specified").id,
+        &message.sticker,
+    )
+    .await
+}
+
+/// Check that the sticker set of the `sticker` was stolen by the bot for the user `user_id`
+/// and ask the user for the new position of the `sticker`
+async fn process_sticker_to_reorder<S: Storage>(
+    bot: &Scheduler,
+    fsm: &Context<S>,
+    client: &Client,
+    chat_id: i64,
+    message_id: i64,
+    user_id: i64,
+    sticker: &Sticker,
+) -> HandlerResult {
+    let set_name = match sticker.set_name.as_deref() {
+        Some(set_name) => set_name,
+        None => {
+            bot.send(SendMessage::new(
+                chat_id,
+                "This sticker is without sticker pack. Try to send another sticker.",
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    if !check_set_owner(bot, client, chat_id, user_id, set_name).await? {
+        return Ok(EventReturn::Finish);
+    }
+
+    let sticker_set = bot.send(GetStickerSet::new(set_name)).await?;
+    let stickers_len = sticker_set.stickers.len();
+
+    let position = match sticker_set
+        .stickers
+        .iter()
+        .position(|set_sticker| set_sticker.file_unique_id == sticker.file_unique_id)
+    {
+        Some(position) => position,
+        None => {
+            bot.send(
+                SendMessage::new(chat_id, "This sticker isn't in the sticker pack anymore.")
+                    .reply_parameters(ReplyParameters::new(message_id).chat_id(chat_id)),
+            )
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    fsm.set_value(
+        "reorder_sticker",
+        (Box::<str>::from(sticker.file_id.as_ref()), stickers_len),
+    )
+    .await
+    .map_err(Into::into)?;
+
+    fsm.set_state(ReorderStickersState::GetPosition)
+        .await
+        .map_err(Into::into)?;
+
+    let prefix = CallbackDataPrefix::Reorder.as_str();
+
+    bot.send(
+        SendMessage::new(
+            chat_id,
+            format!(
+                "This sticker is at position {position} of {stickers_len}. \
+                Send me the new position for it or use the buttons below.",
+                position = html_code((position + 1).to_string()),
+                stickers_len = html_code(stickers_len.to_string()),
+            ),
+        )
+        .parse_mode(ParseMode::HTML)
+        .reply_parameters(ReplyParameters::new(message_id).chat_id(chat_id))
+        .reply_markup(InlineKeyboardMarkup::new([[
+            InlineKeyboardButton::new("Move to front").callback_data(format!("{prefix}f")),
+            InlineKeyboardButton::new("Move to end").callback_data(format!("{prefix}e")),
+        ]])),
+    )
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
+
+pub async fn get_new_sticker_position<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+) -> HandlerResult {
+    // only panic if i'm forget call fsm.set_value() in function process_sticker_to_reorder()
+    let (_, stickers_len): (Box<str>, usize) = fsm
+        .get_value("reorder_sticker")
+        .await
+        .map_err(Into::into)?
+        .expect("Sticker to reorder should be set");
+
+    let position = match message.text.trim().parse::<usize>() {
+        Ok(position) if (1..=stickers_len).contains(&position) => position,
+        _ => {
+            bot.send(SendMessage::new(
+                message.chat.id(),
+                format!("Please send me a number from 1 to {stickers_len}."),
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    move_sticker(&bot, &fsm, message.chat.id(), position - 1).await
+}
+
+pub async fn process_reorder_buttons<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    callback_query: CallbackQuery,
+    fsm: Context<S>,
+) -> HandlerResult {
+    let chat_id = match callback_query.chat_id() {
+        Some(chat_id) => chat_id,
+        None => return Ok(EventReturn::Finish),
+    };
+
+    // i guarantee that there will be `Some()`
+    let message_data = callback_query.data.unwrap();
+    let action = message_data
+        .strip_prefix(CallbackDataPrefix::Reorder.as_str())
+        .expect("Failed to eat callback data prefix");
+
+    bot.send(AnswerCallbackQuery::new(callback_query.id))
+        .await?;
+
+    // only panic if i'm forget call fsm.set_value() in function process_sticker_to_reorder()
+    let (_, stickers_len): (Box<str>, usize) = fsm
+        .get_value("reorder_sticker")
+        .await
+        .map_err(Into::into)?
+        .expect("Sticker to reorder should be set");
+
+    let position = match action {
+        "f" => 0,
+        "e" => stickers_len.saturating_sub(1),
+        _ => return Ok(EventReturn::Finish),
+    };
+
+    move_sticker(&bot, &fsm, chat_id, position).await
+}
+
+/// Move the sticker, that the user chose, to the `position` (starts from 0)
+/// and wait for the next sticker to move
+async fn move_sticker<S: Storage>(
+    bot: &Scheduler,
+    fsm: &Context<S>,
+    chat_id: i64,
+    position: usize,
+) -> HandlerResult {
+    // only panic if i'm forget call fsm.set_value() in function process_sticker_to_reorder()
+    let (file_id, _): (Box<str>, usize) = fsm
+        .get_value("reorder_sticker")
+        .await
+        .map_err(Into::into)?
+        .expect("Sticker to reorder should be set");
+
+    fsm.set_state(ReorderStickersState::GetSticker)
+        .await
+        .map_err(Into::into)?;
+
+    if let Err(error) = bot
+        .send(SetStickerPositionInSet::new(
+            file_id.as_ref(),
+            position as i64,
+        ))
+        .await
+    {
+        error!(?error, "Error occurred while changing sticker position: ");
+
+        send_default_error_message(bot, chat_id).await?;
+
+        return Ok(EventReturn::Finish);
+    }
+
+    bot.send(
+        SendMessage::new(
+            chat_id,
+            format!(
+                "The sticker was moved to position {position}. \
+                Send me the next sticker to move or use /cancel.",
+                position = html_code((position + 1).to_string())
+            ),
+        )
+        .parse_mode(ParseMode::HTML),
+    )
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
@@ -0,0 +1,289 @@
+use grammers_client::Client;
+use telers::{
+    Extension,
+    enums::ParseMode,
+    event::{EventReturn, telegram::HandlerResult},
+    fsm::{Context, Storage},
+    methods::{
+        AnswerCallbackQuery, EditMessageText, GetStickerSet, SendMessage, SetStickerEmojiList,
+    },
+    types::{
+        CallbackQuery, InlineKeyboardButton, InlineKeyboardMarkup, MessageSticker, MessageText,
+        ReplyParameters, Sticker,
+    },
+    utils::text::{html_code, html_quote, html_text_link},
+};
+use tracing::error;
+
+use crate::{
+    core::helpers::{
+        common::parse_emoji_list,
+        constants::{MAX_STICKER_EMOJI_LENGTH, TELEGRAM_STICKER_SET_URL},
+    },
+    presentation::{
+        commands::{
+            common::{check_set_owner, replied_sticker, send_default_error_message},
+            states::{callback_data::CallbackDataPrefix, set_emoji::SetEmojiState},
+        },
+        jobs::{Job, JobQueue, SetStickerSetEmoji},
+        scheduler::Scheduler,
+    },
+};
+
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
+pub async fn set_emoji_handler<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+    Extension(client): Extension<Client>,
+) -> HandlerResult {
+    fsm.finish().await.map_err(Into::into)?;
+
+    fsm.set_state(SetEmojiState::GetSticker)
+        .await
+        .map_err(Into::into)?;
+
+    if let Some((reply_id, sticker)) = replied_sticker(&message) {
+        return process_sticker_to_set_emoji(
+            &bot,
+            &fsm,
+            &client,
+            message.chat.id(),
+            reply_id,
+            // only panic if messages uses in channels, but i'm using private filter
+            message.from.expect("user not specified").id,
+            sticker,
+        )
+        .await;
+    }
+
+    bot.send(SendMessage::new(
+        message.chat.id(),
+        "Send me a sticker from your stolen sticker pack that you want to change emoji for:",
+    ))
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
+
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
+pub async fn get_sticker_to_set_emoji<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageSticker,
+    fsm: Context<S>,
+    Extension(client): Extension<Client>,
+) -> HandlerResult {
+    process_sticker_to_set_emoji(
+        &bot,
+        &fsm,
+        &client,
+        message.chat.id(),
+        message.id,
+        // only panic if messages uses in channels, but i'm using private filter
+        message.from.expect("user not specified").id,
+        &message.sticker,
+    )
+    .await
+}
+
+/// Check that the sticker set of the `sticker` was stolen by the bot for the user `user_id`
+/// and ask the user for the new emoji of the `sticker`
+async fn process_sticker_to_set_emoji<S: Storage>(
+    bot: &Scheduler,
+    fsm: &Context<S>,
+    client: &Client,
+    chat_id: i64,
+    message_id: i64,
+    user_id: i64,
+    sticker: &Sticker,
+) -> HandlerResult {
+    let set_name = match sticker.set_name.as_deref() {
+        Some(set_name) => set_name,
+        None => {
+            bot.send(SendMessage::new(
+                chat_id,
+                "This sticker is without sticker pack. Try to send another sticker.",
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    if !check_set_owner(bot, client, chat_id, user_id, set_name).await? {
+        return Ok(EventReturn::Finish);
+    }
+
+    fsm.set_value(
+        "set_emoji_sticker",
+        (
+            Box::<str>::from(set_name),
+            Box::<str>::from(sticker.file_id.as_ref()),
+        ),
+    )
+    .await
+    .map_err(Into::into)?;
+    fsm.set_value("set_emoji_whole_set", false)
+        .await
+        .map_err(Into::into)?;
+
+    fsm.set_state(SetEmojiState::GetEmoji)
+        .await
+        .map_err(Into::into)?;
+
+    bot.send(
+        SendMessage::new(
+            chat_id,
+            format!(
+                "Now send me the new emoji for this sticker (up to {MAX_STICKER_EMOJI_LENGTH}). \
+                You can also set the same emoji for all stickers of the sticker pack."
+            ),
+        )
+        .reply_parameters(ReplyParameters::new(message_id).chat_id(chat_id))
+        .reply_markup(InlineKeyboardMarkup::new([[InlineKeyboardButton::new(
+            "Set for the entire pack",
+        )
+        .callback_data(CallbackDataPrefix::SetEmoji.as_str())]])),
+    )
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
+
+/// Switch to the bulk mode: the emoji will be set for all stickers of the sticker set
+pub async fn process_set_emoji_button<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    callback_query: CallbackQuery,
+    fsm: Context<S>,
+) -> HandlerResult {
+    let (chat_id, message_id) = match (callback_query.chat_id(), callback_query.message_id()) {
+        (Some(chat_id), Some(message_id)) => (chat_id, message_id),
+        _ => return Ok(EventReturn::Finish),
+    };
+
+    bot.send(AnswerCallbackQuery::new(callback_query.id))
+        .await?;
+
+    fsm.set_value("set_emoji_whole_set", true)
+        .await
+        .map_err(Into::into)?;
+
+    bot.send(
+        EditMessageText::new(format!(
+            "Now send me the emoji for all stickers of the sticker pack (up to {MAX_STICKER_EMOJI_LENGTH}). \
+            The current emoji of all stickers will be replaced."
+        ))
+        .chat_id(chat_id)
+        .message_id(message_id),
+    )
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
+
+pub async fn get_new_sticker_emoji<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+    Extension(job_queue): Extension<JobQueue>,
+) -> HandlerResult {
+    let emoji_list = match parse_emoji_list(&message.text) {
+        Some(emoji_list) => emoji_list,
+        None => {
+            bot.send(SendMessage::new(
+                message.chat.id(),
+                format!("Please send me only emoji, from 1 to {MAX_STICKER_EMOJI_LENGTH} of them."),
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    // only panic if i'm forget call fsm.set_value() in function process_sticker_to_set_emoji()
+    let (set_name, file_id): (Box<str>, Box<str>) = fsm
+        .get_value("set_emoji_sticker")
+        .await
+        .map_err(Into::into)?
+        .expect("Sticker to set emoji should be set");
+    let whole_set: bool = fsm
+        .get_value("set_emoji_whole_set")
+        .await
+        .map_err(Into::into)?
+        .unwrap_or_default();
+
+    fsm.set_state(SetEmojiState::GetSticker)
+        .await
+        .map_err(Into::into)?;
+
+    if !whole_set {
+        if let Err(error) = bot
+            .send(SetStickerEmojiList::new(file_id.as_ref(), emoji_list))
+            .await
+        {
+            error!(
+                ?error,
+                ?set_name,
+                "Error occurred while setting sticker emoji: "
+            );
+
+            send_default_error_message(&bot, message.chat.id()).await?;
+
+            return Ok(EventReturn::Finish);
+        }
+
+        bot.send(SendMessage::new(
+            message.chat.id(),
+            "Done! Send me the next sticker to change its emoji or use /cancel.",
+        ))
+        .await?;
+
+        return Ok(EventReturn::Finish);
+    }
+
+    let sticker_set = bot.send(GetStickerSet::new(set_name.as_ref())).await?;
+
+    let link = html_text_link(
+        html_quote(&sticker_set.title),
+        format!("{TELEGRAM_STICKER_SET_URL}{set_name}"),
+    );
+    let stickers_len = sticker_set.stickers.len();
+
+    // every sticker is a separate request, so it's done by the workers
+    let position = match job_queue.try_enqueue(Job::SetStickerSetEmoji(SetStickerSetEmoji {
+        chat_id: message.chat.id(),
+        sticker_set,
+        emoji_list,
+    })) {
+        Ok(position) => position,
+        Err(err) => {
+            error!(?err, "Error occurred while enqueueing set emoji job: ");
+
+            bot.send(SendMessage::new(
+                message.chat.id(),
+                "Sorry, I'm too busy right now. Please try again in a few minutes.",
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    bot.send(
+        SendMessage::new(
+            message.chat.id(),
+            format!(
+                "Changing the emoji of {stickers_len} stickers of {link}.. Your place in the queue: {position}. \
+                I'll send you a message when it's done. Meanwhile, send me the next sticker to change its emoji or use /cancel.",
+                stickers_len = html_code(stickers_len.to_string()),
+                position = html_code(position.to_string())
+            ),
+        )
+        .parse_mode(ParseMode::HTML),
+    )
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
@@ -1,15 +1,15 @@
 use telers::{
-    Bot,
+    Extension,
     event::{EventReturn, telegram::HandlerResult},
     fsm::{Context, Storage},
     methods::SendMessage,
     types::Message,
 };
 
-use crate::core::helpers::texts::start_message;
+use crate::{core::helpers::texts::start_message, presentation::scheduler::Scheduler};
 
 pub async fn start_handler<S: Storage>(
-    bot: Bot,
+    Extension(bot): Extension<Scheduler>,
     message: Message,
     fsm: Context<S>,
 ) -> HandlerResult {
@@ -1,5 +1,5 @@
 use telers::{
-    Bot, Extension,
+    Extension,
     enums::ParseMode,
     errors::{HandlerError, TelegramErrorKind, session::ErrorKind},
     event::{EventReturn, telegram::HandlerResult},
@@ -20,11 +20,11 @@ use crate::{
         stats::{GlobalStats, GreaterThan, PersonalStats},
         texts::{global_stats_message, personal_stats_message},
     },
-    presentation::commands::states::callback_data::CallbackDataPrefix,
+    presentation::{commands::states::callback_data::CallbackDataPrefix, scheduler::Scheduler},
 };
 
 pub async fn stats_handler<S, UoWFactory>(
-    bot: Bot,
+    Extension(bot): Extension<Scheduler>,
     message: Message,
     fsm: FSMContext<S>,
     Extension(uow_factory): Extension<UoWFactory>,
@@ -56,7 +56,7 @@ where
 }
 
 pub async fn process_buttons<UoWFactory>(
-    bot: Bot,
+    Extension(bot): Extension<Scheduler>,
     callback_query: CallbackQuery,
     Extension(uow_factory): Extension<UoWFactory>,
 ) -> HandlerResult
@@ -206,7 +206,7 @@ where
 }
 
 async fn send_edit_message(
-    bot: &Bot,
+    bot: &Scheduler,
     text: impl Into<String>,
     chat_id: i64,
     message_id: i64,
@@ -0,0 +1,71 @@
+use sqlx::{Database, Pool};
+use telers::event::simple::HandlerResult;
+use tracing::{debug, error};
+
+use crate::{
+    application::{
+        common::traits::uow::{UoW as _, UoWFactory as UoWFactoryTrait},
+        set::repository::SetRepo,
+        steal_job::{dto::get_all::GetAll, repository::StealJobRepo},
+        user::repository::UserRepo,
+    },
+    domain::entities::steal_job::StealJobKind,
+    infrastructure::database::{
+        repositories::{set::SetRepoImpl, steal_job::StealJobRepoImpl, user::UserRepoImpl},
+        uow::UoWFactory,
+    },
+    presentation::jobs::{Job, JobQueue},
+};
+
+/// Put all steal and add stickers jobs that were interrupted or didn't start (for example, because the bot
+/// was restarted) back into the job queue
+pub async fn resume_steal_jobs<DB>(pool: Pool<DB>, job_queue: JobQueue) -> HandlerResult
+where
+    DB: Database,
+    for<'a> UserRepoImpl<&'a mut DB::Connection>: UserRepo,
+    for<'a> SetRepoImpl<&'a mut DB::Connection>: SetRepo,
+    for<'a> StealJobRepoImpl<&'a mut DB::Connection>: StealJobRepo,
+{
+    tokio::spawn(async move {
+        let uow_factory = UoWFactory::new(pool.clone());
+        let mut uow = uow_factory.create_uow();
+
+        let result = match uow.steal_job_repo().await {
+            Ok(repo) => repo,
+            Err(err) => {
+                error!(?err, "Failed to start transaction: ");
+                return;
+            }
+        }
+        .get_all(GetAll::new(Some(false)))
+        .await;
+
+        let jobs = match result {
+            Ok(jobs) => jobs,
+            Err(err) => {
+                error!(
+                    ?err,
+                    "Error occurred while trying to get unfinished steal jobs: "
+                );
+                return;
+            }
+        };
+
+        debug!("Resuming {} unfinished steal jobs..", jobs.len());
+
+        for job in jobs {
+            let short_name = job.short_name.clone();
+
+            let job = match job.kind {
+                StealJobKind::Steal => Job::ResumeStealJob(job),
+                StealJobKind::AddStickers => Job::AddStickers(job),
+            };
+
+            if let Err(err) = job_queue.enqueue(job).await {
+                error!(?err, ?short_name, "Failed to resume steal job: ");
+            }
+        }
+    });
+
+    Ok(())
+}
@@ -1,58 +1,107 @@
 use telers::{
-    Bot, Extension,
+    Extension,
     enums::ParseMode,
-    errors::{HandlerError, TelegramErrorKind, session::ErrorKind},
+    errors::{HandlerError, SessionErrorKind, TelegramErrorKind, session::ErrorKind},
     event::{EventReturn, telegram::HandlerResult},
     fsm::{Context, Storage},
-    methods::{CreateNewStickerSet, DeleteMessage, GetMe, GetStickerSet, SendMessage},
-    types::{InputFile, InputSticker, MessageSticker, MessageText},
+    methods::{AnswerCallbackQuery, EditMessageText, GetMe, GetStickerSet, SendMessage},
+    types::{
+        CallbackQuery, InlineKeyboardButton, InlineKeyboardMarkup, MessageSticker, MessageText,
+        Sticker,
+    },
     utils::text::{html_code, html_quote, html_text_link},
 };
 use tracing::error;
 
 use crate::{
     application::{
-        common::traits::uow::UoWFactory as UoWFactoryTrait, interactors::create_set::create_set,
-        set::dto::create::Create as CreateSet,
+        common::traits::uow::{UoW as _, UoWFactory as UoWFactoryTrait},
+        set::{dto::get_by_source_short_name::GetBySourceShortName, repository::SetRepo as _},
     },
-    core::helpers::constants::{
-        CREATE_SET_IN_ONE_GO_LENGTH_LIMIT, MAX_SET_TITLE_LENGTH, MIN_SET_TITLE_LENGTH,
+    core::helpers::{
+        common::{
+            create_set_in_one_go_length_limit, make_sticker_set_short_name, max_sticker_set_length,
+            missing_stickers, parse_sticker_ranges, parse_sticker_set_name,
+            sticker_set_short_name_alternatives, stolen_sticker_type,
+        },
+        constants::{STICKERS_NUMBER_PER_SELECT_PAGE, TELEGRAM_STICKER_SET_URL},
     },
-    presentation::commands::{
-        common::{add_stickers, send_default_error_message},
-        states::steal_sticker_set::StealStickerSetState,
+    presentation::{
+        commands::{
+            common::{check_set_title, replied_sticker, send_default_error_message},
+            states::{callback_data::CallbackDataPrefix, steal_sticker_set::StealStickerSetState},
+        },
+        jobs::{AddStickers, JobQueue, StealStickerSet},
+        scheduler::Scheduler,
     },
 };
-use crate::{
-    core::helpers::common::{generate_sticker_set_name_and_link, sticker_format},
-    core::helpers::texts::sticker_set_message,
-};
 
-pub async fn steal_sticker_set_handler<S: Storage>(
-    bot: Bot,
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
+pub async fn steal_sticker_set_handler<S, UoWFactory>(
+    Extension(bot): Extension<Scheduler>,
     message: MessageText,
     fsm: Context<S>,
-) -> HandlerResult {
+    Extension(uow_factory): Extension<UoWFactory>,
+) -> HandlerResult
+where
+    S: Storage,
+    UoWFactory: UoWFactoryTrait,
+{
     fsm.finish().await.map_err(Into::into)?;
 
     fsm.set_state(StealStickerSetState::StealStickerSetName)
         .await
         .map_err(Into::into)?;
 
+    if let Some((_, sticker)) = replied_sticker(&message) {
+        let set_name = match sticker.set_name.as_deref() {
+            Some(set_name) => set_name,
+            None => {
+                bot.send(SendMessage::new(
+                    message.chat.id(),
+                    "This sticker is without sticker pack! Try to send another sticker pack.",
+                ))
+                .await?;
+
+                return Ok(EventReturn::Finish);
+            }
+        };
+
+        return process_sticker_set_name(
+            &bot,
+            &fsm,
+            &uow_factory,
+            message.chat.id(),
+            // only panic if messages uses in channels, but i'm using private filter
+            message.from.expect("user not specified").id,
+            set_name,
+            "This sticker is without sticker pack. Try to send another sticker pack.",
+        )
+        .await;
+    }
+
     bot.send(SendMessage::new(
         message.chat.id(),
-        "Send me a sticker and I will steal this sticker pack for you:",
+        "Send me a sticker, a link to a sticker pack or its name and I will steal this sticker pack for you:",
     ))
     .await?;
 
     Ok(EventReturn::Finish)
 }
 
-pub async fn get_sticker_set_name<S: Storage>(
-    bot: Bot,
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
+pub async fn get_sticker_set_name<S, UoWFactory>(
+    Extension(bot): Extension<Scheduler>,
     message: MessageSticker,
     fsm: Context<S>,
-) -> HandlerResult {
+    Extension(uow_factory): Extension<UoWFactory>,
+) -> HandlerResult
+where
+    S: Storage,
+    UoWFactory: UoWFactoryTrait,
+{
     let set_name = match message.sticker.set_name {
         Some(sticker_set_name) => sticker_set_name,
         None => {
@@ -66,240 +115,879 @@ pub async fn get_sticker_set_name<S: Storage>(
         }
     };
 
-    if let Err(ref error) = bot.send(GetStickerSet::new(&*set_name)).await {
-        if matches!(error, ErrorKind::Telegram(TelegramErrorKind::BadRequest { message }) if **message == *"Bad Request: STICKERSET_INVALID")
-        {
+    process_sticker_set_name(
+        &bot,
+        &fsm,
+        &uow_factory,
+        message.chat.id(),
+        // only panic if messages uses in channels, but i'm using private filter
+        message.from.expect("user not specified").id,
+        &set_name,
+        "This sticker is without sticker pack. Try to send another sticker pack.",
+    )
+    .await
+}
+
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
+pub async fn get_sticker_set_name_from_text<S, UoWFactory>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+    Extension(uow_factory): Extension<UoWFactory>,
+) -> HandlerResult
+where
+    S: Storage,
+    UoWFactory: UoWFactoryTrait,
+{
+    let set_name = match parse_sticker_set_name(&message.text) {
+        Some(set_name) => set_name,
+        None => {
             bot.send(SendMessage::new(
                 message.chat.id(),
-                "This sticker is without sticker pack. Try to send another sticker pack.",
+                format!(
+                    "I can't find a sticker pack name in this message. Send me a sticker, \
+                    a link like {TELEGRAM_STICKER_SET_URL}<name> or only the name of the sticker pack."
+                ),
             ))
             .await?;
 
             return Ok(EventReturn::Finish);
         }
+    };
 
-        error!(
-            ?error,
-            "Error occurred while getting sticker set name to steal: "
-        );
+    process_sticker_set_name(
+        &bot,
+        &fsm,
+        &uow_factory,
+        message.chat.id(),
+        // only panic if messages uses in channels, but i'm using private filter
+        message.from.expect("user not specified").id,
+        set_name,
+        "Sticker pack with this name doesn't exist. Try to send another sticker pack.",
+    )
+    .await
+}
 
-        send_default_error_message(&bot, message.chat.id()).await?;
+/// Check that sticker set `set_name` exists and move on to the selection of stickers to steal.
+/// If it doesn't exist, send `not_exist_text` to the user. If the user has already stolen it,
+/// ask what to do with the existing copy first.
+async fn process_sticker_set_name<S, UoWFactory>(
+    bot: &Scheduler,
+    fsm: &Context<S>,
+    uow_factory: &UoWFactory,
+    chat_id: i64,
+    user_id: i64,
+    set_name: &str,
+    not_exist_text: &'static str,
+) -> HandlerResult
+where
+    S: Storage,
+    UoWFactory: UoWFactoryTrait,
+{
+    let sticker_set = match bot.send(GetStickerSet::new(set_name)).await {
+        Ok(sticker_set) => sticker_set,
+        Err(error) => {
+            if matches!(&error, ErrorKind::Telegram(TelegramErrorKind::BadRequest { message }) if **message == *"Bad Request: STICKERSET_INVALID")
+            {
+                bot.send(SendMessage::new(chat_id, not_exist_text)).await?;
+
+                return Ok(EventReturn::Finish);
+            }
+
+            error!(
+                ?error,
+                "Error occurred while getting sticker set name to steal: "
+            );
+
+            send_default_error_message(bot, chat_id).await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    let mut uow = uow_factory.create_uow();
+
+    let copies = uow
+        .set_repo()
+        .await
+        .map_err(HandlerError::new)?
+        .get_by_source_short_name(GetBySourceShortName::new(user_id, set_name))
+        .await
+        .map_err(HandlerError::new)?;
+
+    // the latest copy is most likely the one the user remembers
+    if let Some(copy) = copies.into_iter().max_by_key(|copy| copy.created_at) {
+        bot.send(
+            SendMessage::new(
+                chat_id,
+                format!(
+                    "You have already stolen this sticker pack: {copy_link}. What do you want to do?",
+                    copy_link = html_text_link(
+                        html_quote(&copy.title),
+                        format!("{TELEGRAM_STICKER_SET_URL}{}", copy.short_name)
+                    ),
+                ),
+            )
+            .parse_mode(ParseMode::HTML)
+            .reply_markup(existing_copy_keyboard(&copy.short_name)),
+        )
+        .await?;
+
+        fsm.set_value("steal_sticker_set_name", set_name)
+            .await
+            .map_err(Into::into)?;
+        fsm.set_value("steal_existing_copy", copy.short_name)
+            .await
+            .map_err(Into::into)?;
+
+        fsm.set_state(StealStickerSetState::ExistingCopy)
+            .await
+            .map_err(Into::into)?;
 
         return Ok(EventReturn::Finish);
     }
 
-    fsm.set_value("steal_sticker_set_name", set_name.as_ref())
+    start_select_stickers(bot, fsm, chat_id, set_name, &sticker_set.stickers).await
+}
+
+/// Send the keyboard to select stickers of the sticker set `set_name` to steal
+async fn start_select_stickers<S: Storage>(
+    bot: &Scheduler,
+    fsm: &Context<S>,
+    chat_id: i64,
+    set_name: &str,
+    stickers: &[Sticker],
+) -> HandlerResult {
+    // all stickers are selected by default, so user can just press "Done" to steal the whole sticker set
+    let selected = vec![true; stickers.len()];
+    let emojis: Vec<Box<str>> = stickers
+        .iter()
+        .map(|sticker| sticker.emoji.clone().unwrap_or_default())
+        .collect();
+
+    bot.send(
+        SendMessage::new(chat_id, select_stickers_message(&selected))
+            .reply_markup(select_stickers_keyboard(&selected, &emojis, 1)),
+    )
+    .await?;
+
+    fsm.set_value("steal_sticker_set_name", set_name)
         .await
         .map_err(Into::into)?;
-
-    fsm.set_state(StealStickerSetState::CreateNewStickerSet)
+    // the stickers are selected by their indexes, so they are kept instead of refetching the source later,
+    // because stickers could be added, removed or moved in it meanwhile
+    fsm.set_value("steal_stickers", stickers)
+        .await
+        .map_err(Into::into)?;
+    fsm.set_value("steal_selected_stickers", selected)
+        .await
+        .map_err(Into::into)?;
+    fsm.set_value("steal_sticker_emojis", emojis)
         .await
         .map_err(Into::into)?;
 
-    bot.send(SendMessage::new(
-        message.chat.id(),
-        "Now enter name for your new sticker pack (1-64 characters):",
-    ))
-    .await?;
+    fsm.set_state(StealStickerSetState::SelectStickers)
+        .await
+        .map_err(Into::into)?;
 
     Ok(EventReturn::Finish)
 }
 
-/// ### Panics
-/// - Panics if user is unknown (only if message sent in channel)
-pub async fn create_new_sticker_set<S, UoWFactory>(
-    bot: Bot,
-    message: MessageText,
+fn existing_copy_keyboard(copy_short_name: &str) -> InlineKeyboardMarkup {
+    let prefix = CallbackDataPrefix::ExistingCopy.as_str();
+
+    InlineKeyboardMarkup::new([
+        [InlineKeyboardButton::new("Open existing copy").url(format!(
+            "https://{TELEGRAM_STICKER_SET_URL}{copy_short_name}"
+        ))],
+        [InlineKeyboardButton::new("Sync missing stickers into it")
+            .callback_data(format!("{prefix}s"))],
+        [
+            InlineKeyboardButton::new("Make another copy anyway")
+                .callback_data(format!("{prefix}c")),
+        ],
+    ])
+}
+
+pub async fn process_existing_copy_buttons<S, UoWFactory>(
+    Extension(bot): Extension<Scheduler>,
+    callback_query: CallbackQuery,
     fsm: Context<S>,
+    Extension(job_queue): Extension<JobQueue>,
     Extension(uow_factory): Extension<UoWFactory>,
 ) -> HandlerResult
 where
-    UoWFactory: UoWFactoryTrait,
     S: Storage,
+    UoWFactory: UoWFactoryTrait,
 {
-    // if user enter wrong sticker set title, process it
-    let new_set_title = if message.text.len() > 64 {
-        bot.send(SendMessage::new(
-            message.chat.id(),
-            format!(
-                "Too long name! Please enter a name up to {max_len} characters long.",
-                max_len = html_code(MAX_SET_TITLE_LENGTH.to_string())
-            ),
-        ))
-        .await?;
+    let (chat_id, message_id) = match (callback_query.chat_id(), callback_query.message_id()) {
+        (Some(chat_id), Some(message_id)) => (chat_id, message_id),
+        _ => return Ok(EventReturn::Finish),
+    };
 
-        return Ok(EventReturn::Finish);
-    } else if message.text.len() < 1 {
-        bot.send(SendMessage::new(
-            message.chat.id(),
-            format!(
-                "Too short name! Please enter a name between {min_len} and {max_len} characters long.",
-                min_len = html_code(MIN_SET_TITLE_LENGTH.to_string()),
-                max_len = html_code(MAX_SET_TITLE_LENGTH.to_string())
-            ),
-        ))
-        .await?;
+    // i guarantee that there will be `Some()`
+    let message_data = callback_query.data.unwrap();
+    let action = message_data
+        .strip_prefix(CallbackDataPrefix::ExistingCopy.as_str())
+        .expect("Failed to eat callback data prefix");
 
-        return Ok(EventReturn::Finish);
-    } else {
-        message.text
-    };
+    bot.send(AnswerCallbackQuery::new(callback_query.id))
+        .await?;
 
-    // only panic if i'm forget call fsm.set_value() in function steal_sticker_set_name()
-    let steal_sticker_set_name: Box<str> = fsm
+    // only panic if i'm forget call fsm.set_value() in function process_sticker_set_name()
+    let set_name: Box<str> = fsm
         .get_value("steal_sticker_set_name")
         .await
         .map_err(Into::into)?
         .expect("Sticker set name for sticker set user want steal should be set");
+    let copy_short_name: Box<str> = fsm
+        .get_value("steal_existing_copy")
+        .await
+        .map_err(Into::into)?
+        .expect("Short name of the existing copy should be set");
 
-    fsm.finish().await.map_err(Into::into)?;
+    let sticker_set = bot.send(GetStickerSet::new(set_name.as_ref())).await?;
 
-    let steal_sticker_set = bot
-        .send(GetStickerSet::new(steal_sticker_set_name.as_ref()))
-        .await?;
+    match action {
+        "c" => {
+            bot.send(
+                EditMessageText::new("Okay, let's make another copy of this sticker pack.")
+                    .chat_id(chat_id)
+                    .message_id(message_id),
+            )
+            .await?;
 
-    let steal_stickers_from_sticker_set = steal_sticker_set.stickers;
+            start_select_stickers(&bot, &fsm, chat_id, &set_name, &sticker_set.stickers).await
+        }
+        "s" => {
+            fsm.finish().await.map_err(Into::into)?;
 
-    // cant panic because bot cant be without username
-    let bot_username = bot
-        .send(GetMe::new())
-        .await?
-        .username
-        .expect("bot without username :/");
+            let copy = match bot.send(GetStickerSet::new(copy_short_name.as_ref())).await {
+                Ok(copy) => copy,
+                Err(ErrorKind::Telegram(TelegramErrorKind::BadRequest { message }))
+                    if message.as_ref() == "Bad Request: STICKERSET_INVALID" =>
+                {
+                    bot.send(
+                        EditMessageText::new(
+                            "Your copy of this sticker pack was deleted. Use /stealpack to make a new one.",
+                        )
+                        .chat_id(chat_id)
+                        .message_id(message_id),
+                    )
+                    .await?;
 
-    // only panic if bot using in channels, but i'm using private filter in launch function
-    let user_id = message.from.expect("user without id").id;
+                    return Ok(EventReturn::Finish);
+                }
+                Err(error) => return Err(error.into()),
+            };
+
+            let mut new_stickers = missing_stickers(sticker_set.stickers, &copy.stickers);
+            let new_stickers_len = new_stickers.len();
+            let remaining =
+                max_sticker_set_length(&copy.sticker_type).saturating_sub(copy.stickers.len());
+            new_stickers.truncate(remaining);
+
+            let copy_link = html_text_link(
+                html_quote(&copy.title),
+                format!("{TELEGRAM_STICKER_SET_URL}{copy_short_name}"),
+            );
+
+            let text = if new_stickers_len == 0 {
+                format!("{copy_link} already has all the stickers of this sticker pack.")
+            } else if new_stickers.is_empty() {
+                format!(
+                    "{copy_link} is completely filled, so I can't add {new_len} missing sticker(s) into it.",
+                    new_len = html_code(new_stickers_len.to_string())
+                )
+            } else {
+                let user_id = callback_query.from.id;
+                let added_len = new_stickers.len();
+
+                let position = match job_queue
+                    .try_enqueue_add_stickers(
+                        &uow_factory,
+                        AddStickers {
+                            tg_id: user_id,
+                            short_name: copy_short_name.to_string(),
+                            title: copy.title.to_string(),
+                            sticker_type: copy.sticker_type.to_string(),
+                            stickers: new_stickers,
+                        },
+                    )
+                    .await
+                {
+                    Ok(position) => position,
+                    Err(err) => {
+                        error!(?err, "Error occurred while enqueueing add stickers job: ");
+
+                        bot.send(SendMessage::new(
+                            chat_id,
+                            "Sorry, I'm too busy right now. Please try again in a few minutes.",
+                        ))
+                        .await?;
+
+                        return Ok(EventReturn::Finish);
+                    }
+                };
+
+                let mut text = format!(
+                    "Adding {added_len} missing sticker(s) into {copy_link}.. Your place in the queue: {position}.",
+                    added_len = html_code(added_len.to_string()),
+                    position = html_code(position.to_string())
+                );
+                if added_len < new_stickers_len {
+                    text.push_str(&format!(
+                        " Only {added_len} of {new_len} missing stickers fit into your sticker pack.",
+                        added_len = html_code(added_len.to_string()),
+                        new_len = html_code(new_stickers_len.to_string())
+                    ));
+                }
 
-    // prepare name for new sticker set and link to use it in message later
-    let (mut new_set_name, mut new_set_link) =
-        generate_sticker_set_name_and_link(11, &bot_username);
+                text
+            };
 
-    let message_delete = bot.send(SendMessage::new(
-        message.chat.id(),
-        format!(
-            "Stealing sticker pack with name {title} for you.. It may take up to a several minutes, if this sticker pack \
-            containing more than {CREATE_SET_IN_ONE_GO_LENGTH_LIMIT} stickers.",
-            title = html_code(html_quote(&new_set_title))
-        ),
-    ).parse_mode(ParseMode::HTML))
-    .await?;
+            bot.send(
+                EditMessageText::new(text)
+                    .chat_id(chat_id)
+                    .message_id(message_id)
+                    .parse_mode(ParseMode::HTML),
+            )
+            .await?;
 
-    let (limit_sticker_set_length, more_than_limit) =
-        if steal_stickers_from_sticker_set.len() > CREATE_SET_IN_ONE_GO_LENGTH_LIMIT {
-            (CREATE_SET_IN_ONE_GO_LENGTH_LIMIT, true)
-        } else {
-            (steal_stickers_from_sticker_set.len(), false)
-        };
+            Ok(EventReturn::Finish)
+        }
+        _ => Ok(EventReturn::Finish),
+    }
+}
 
-    while let Err(err) = bot
-        .send(CreateNewStickerSet::new(
-            user_id,
-            new_set_name.as_str(),
-            new_set_title.as_ref(),
-            steal_stickers_from_sticker_set
-                .iter()
-                .take(limit_sticker_set_length)
-                .map(|sticker| {
-                    let istick: InputSticker = InputSticker::new(
-                        InputFile::id(sticker.file_id.as_ref()),
-                        sticker_format(sticker),
-                    );
-                    istick.emoji_list(sticker.emoji.clone())
-                }),
-        ))
+pub async fn process_select_stickers_buttons<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    callback_query: CallbackQuery,
+    fsm: Context<S>,
+) -> HandlerResult {
+    let (chat_id, message_id) = match (callback_query.chat_id(), callback_query.message_id()) {
+        (Some(chat_id), Some(message_id)) => (chat_id, message_id),
+        _ => return Ok(EventReturn::Finish),
+    };
+
+    // i guarantee that there will be `Some()`
+    let message_data = callback_query.data.unwrap();
+    let mut message_data = message_data.chars();
+
+    message_data
+        .nth(CallbackDataPrefix::SelectStickers.as_str().len() - 1)
+        .expect("Failed to eat callback data prefix");
+
+    // only panic if i'm forget call fsm.set_value() in function process_sticker_set_name()
+    let mut selected: Vec<bool> = fsm
+        .get_value("steal_selected_stickers")
         .await
-    {
-        match err {
-            ErrorKind::Telegram(err) => {
-                if matches!(&err, TelegramErrorKind::BadRequest { message } if message.as_ref()
-                    == "Bad Request: SHORTNAME_OCCUPY_FAILED")
-                {
-                    (new_set_name, new_set_link) =
-                        generate_sticker_set_name_and_link(11, &bot_username);
-                } else {
-                    error!(
-                        ?err,
-                        ?new_set_name,
-                        "Bad request error occurred while creating new sticker set: "
-                    );
-
-                    bot.send(SendMessage::new(
-                        message.chat.id(),
-                        "Sorry, an error occurred while creating new sticker pack",
-                    ))
-                    .await?;
+        .map_err(Into::into)?
+        .expect("Selected stickers should be set");
+    let emojis: Vec<Box<str>> = fsm
+        .get_value("steal_sticker_emojis")
+        .await
+        .map_err(Into::into)?
+        .expect("Sticker emojis should be set");
 
-                    return Ok(EventReturn::Finish);
-                }
+    let action = message_data.next();
+    let number = message_data.as_str().parse::<usize>().ok();
+
+    let page = match (action, number) {
+        // toggle one sticker
+        (Some('t'), Some(index)) if index < selected.len() => {
+            selected[index] = !selected[index];
+            index / STICKERS_NUMBER_PER_SELECT_PAGE + 1
+        }
+        (Some('p'), Some(page)) => page,
+        (Some('a'), Some(page)) => {
+            selected
+                .iter_mut()
+                .for_each(|is_selected| *is_selected = true);
+            page
+        }
+        (Some('n'), Some(page)) => {
+            selected
+                .iter_mut()
+                .for_each(|is_selected| *is_selected = false);
+            page
+        }
+        (Some('d'), _) => {
+            if !selected.contains(&true) {
+                bot.send(
+                    AnswerCallbackQuery::new(callback_query.id)
+                        .text("Select at least one sticker to steal"),
+                )
+                .await?;
+
+                return Ok(EventReturn::Finish);
             }
-            err => {
-                error!(
-                    ?err,
-                    ?new_set_name,
-                    "Error occurred while creating new sticker set:"
-                );
 
-                bot.send(SendMessage::new(
-                    message.chat.id(),
-                    "Sorry, an error occurred while creating new sticker pack",
+            bot.send(AnswerCallbackQuery::new(callback_query.id))
+                .await?;
+
+            fsm.set_state(StealStickerSetState::CreateNewStickerSet)
+                .await
+                .map_err(Into::into)?;
+
+            bot.send(
+                EditMessageText::new(format!(
+                    "{selected_len} stickers of {stickers_len} will be stolen.",
+                    selected_len = selected.iter().filter(|is_selected| **is_selected).count(),
+                    stickers_len = selected.len()
                 ))
+                .chat_id(chat_id)
+                .message_id(message_id),
+            )
+            .await?;
+
+            bot.send(SendMessage::new(
+                chat_id,
+                "Now enter name for your new sticker pack (1-64 characters):",
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+        _ => {
+            bot.send(AnswerCallbackQuery::new(callback_query.id))
                 .await?;
 
-                return Ok(EventReturn::Finish);
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    bot.send(AnswerCallbackQuery::new(callback_query.id))
+        .await?;
+
+    fsm.set_value("steal_selected_stickers", selected.clone())
+        .await
+        .map_err(Into::into)?;
+
+    let edit_message = EditMessageText::new(select_stickers_message(&selected))
+        .chat_id(chat_id)
+        .message_id(message_id)
+        .reply_markup(select_stickers_keyboard(&selected, &emojis, page));
+
+    if let Err(error) = bot.send(edit_message).await {
+        match &error {
+            ErrorKind::Telegram(TelegramErrorKind::BadRequest { message }) => {
+                // we need to ignore this bad request error
+                if !message.contains("message is not modified") {
+                    return Err(error.into());
+                }
             }
+            _ => return Err(error.into()),
         }
     }
 
-    let mut uow = uow_factory.create_uow();
-
-    create_set(
-        &mut uow,
-        CreateSet::new(user_id, new_set_name.as_str(), new_set_title.as_ref()),
-    )
-    .await
-    .map_err(HandlerError::new)?;
+    Ok(EventReturn::Finish)
+}
 
-    if more_than_limit {
-        let all_stickers_was_added = add_stickers(
-            &bot,
-            user_id,
-            new_set_name.as_ref(),
-            steal_stickers_from_sticker_set[limit_sticker_set_length..].into(),
-        )
+/// Select only the stickers, that user sent as numbers and ranges (`1-20, 35`), other stickers are deselected
+pub async fn select_stickers_from_text<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+) -> HandlerResult {
+    // only panic if i'm forget call fsm.set_value() in function process_sticker_set_name()
+    let mut selected: Vec<bool> = fsm
+        .get_value("steal_selected_stickers")
+        .await
+        .map_err(Into::into)?
+        .expect("Selected stickers should be set");
+    let emojis: Vec<Box<str>> = fsm
+        .get_value("steal_sticker_emojis")
         .await
-        .expect("empty stickers list");
+        .map_err(Into::into)?
+        .expect("Sticker emojis should be set");
 
-        if !all_stickers_was_added {
+    let indexes = match parse_sticker_ranges(&message.text, selected.len()) {
+        Some(indexes) => indexes,
+        None => {
             bot.send(SendMessage::new(
                 message.chat.id(),
                 format!(
-                    "Error occurred while creating new sticker pack {created_pack} but sticker pack was created! \
-                    Due to an error, not all stickers have been stolen. The internal name of this sticker pack: {copy_set_name}.",
-                    created_pack = html_text_link(html_quote(new_set_title), new_set_link),
-                    copy_set_name = html_code(new_set_name)
+                    "I can't understand these numbers. Send me sticker numbers from 1 to {stickers_len} \
+                    or ranges of them, e.g. 1-20, 35.",
+                    stickers_len = selected.len()
                 ),
-            ).parse_mode(ParseMode::HTML))
+            ))
             .await?;
 
             return Ok(EventReturn::Finish);
         }
+    };
+
+    selected.fill(false);
+    for &index in indexes.iter() {
+        selected[index] = true;
     }
 
+    // show the page with the first selected sticker
+    let page = indexes[0] / STICKERS_NUMBER_PER_SELECT_PAGE + 1;
+
     bot.send(
-        SendMessage::new(
-            message.chat.id(),
-            sticker_set_message(&new_set_title, &new_set_name, &new_set_link),
-        )
-        .parse_mode(ParseMode::HTML),
+        SendMessage::new(message.chat.id(), select_stickers_message(&selected))
+            .reply_markup(select_stickers_keyboard(&selected, &emojis, page)),
     )
     .await?;
 
-    // delete unnecessary message
-    bot.send(DeleteMessage::new(
-        message_delete.chat().id(),
-        message_delete.id(),
+    fsm.set_value("steal_selected_stickers", selected)
+        .await
+        .map_err(Into::into)?;
+
+    Ok(EventReturn::Finish)
+}
+
+fn select_stickers_message(selected: &[bool]) -> String {
+    format!(
+        "Choose the stickers you want to steal: {selected_len} of {stickers_len} are selected. \
+        Tap on the stickers to select or deselect them, or send me numbers and ranges of the only stickers \
+        you want to steal, e.g. 1-20, 35. \
+        Press \"Done\" when you're ready.",
+        selected_len = selected.iter().filter(|is_selected| **is_selected).count(),
+        stickers_len = selected.len()
+    )
+}
+
+/// Create keyboard with the stickers of `page` (begins with 1), the buttons to switch pages,
+/// to select or deselect all stickers and to finish the selection
+fn select_stickers_keyboard(
+    selected: &[bool],
+    emojis: &[Box<str>],
+    page: usize,
+) -> InlineKeyboardMarkup {
+    let prefix = CallbackDataPrefix::SelectStickers.as_str();
+    let pages_number = selected
+        .len()
+        .div_ceil(STICKERS_NUMBER_PER_SELECT_PAGE)
+        .max(1);
+    let page = page.clamp(1, pages_number);
+
+    let begin = (page - 1) * STICKERS_NUMBER_PER_SELECT_PAGE;
+    let end = (begin + STICKERS_NUMBER_PER_SELECT_PAGE).min(selected.len());
+
+    let mut buttons: Vec<Vec<InlineKeyboardButton>> = (begin..end)
+        .map(|index| {
+            let mark = if selected[index] { "✅ " } else { "" };
+            let emoji = emojis.get(index).map(|emoji| &**emoji).unwrap_or_default();
+
+            InlineKeyboardButton::new(format!("{mark}{number} {emoji}", number = index + 1))
+                .callback_data(format!("{prefix}t{index}"))
+        })
+        .collect::<Vec<_>>()
+        // 5 stickers in a row
+        .chunks(5)
+        .map(<[_]>::to_vec)
+        .collect();
+
+    if pages_number > 1 {
+        buttons.push(vec![
+            InlineKeyboardButton::new("«").callback_data(format!(
+                "{prefix}p{}",
+                if page == 1 { pages_number } else { page - 1 }
+            )),
+            InlineKeyboardButton::new(format!("Page {page}/{pages_number}"))
+                .callback_data(format!("{prefix}c")),
+            InlineKeyboardButton::new("»").callback_data(format!(
+                "{prefix}p{}",
+                if page == pages_number { 1 } else { page + 1 }
+            )),
+        ]);
+    }
+
+    buttons.push(vec![
+        InlineKeyboardButton::new("Select all").callback_data(format!("{prefix}a{page}")),
+        InlineKeyboardButton::new("Select none").callback_data(format!("{prefix}n{page}")),
+    ]);
+    buttons.push(vec![
+        InlineKeyboardButton::new("Done").callback_data(format!("{prefix}d")),
+    ]);
+
+    InlineKeyboardMarkup::new(buttons)
+}
+
+pub async fn get_new_sticker_set_title<S: Storage>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+) -> HandlerResult {
+    // if user enter wrong sticker set title, process it
+    if !check_set_title(&bot, message.chat.id(), &message.text).await? {
+        return Ok(EventReturn::Finish);
+    }
+
+    fsm.set_value("steal_new_sticker_set_title", &*message.text)
+        .await
+        .map_err(Into::into)?;
+
+    fsm.set_state(StealStickerSetState::GetShortName)
+        .await
+        .map_err(Into::into)?;
+
+    bot.send(SendMessage::new(
+        message.chat.id(),
+        format!(
+            "Now send me a name for the link of your new sticker pack ({TELEGRAM_STICKER_SET_URL}<name>), \
+            e.g. my_cats. It can contain english letters, digits and underscores. \
+            Use /skip if you want me to come up with a random one."
+        ),
     ))
     .await?;
 
     Ok(EventReturn::Finish)
 }
+
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
+pub async fn get_short_name<S, UoWFactory>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+    Extension(job_queue): Extension<JobQueue>,
+    Extension(uow_factory): Extension<UoWFactory>,
+) -> HandlerResult
+where
+    S: Storage,
+    UoWFactory: UoWFactoryTrait,
+{
+    let chat_id = message.chat.id();
+
+    // cant panic because bot cant be without username
+    let bot_username = bot
+        .send(GetMe::new())
+        .await?
+        .username
+        .expect("bot without username :/");
+
+    let short_name = match make_sticker_set_short_name(&message.text, &bot_username) {
+        Ok(short_name) => short_name,
+        Err(err) => {
+            bot.send(SendMessage::new(
+                chat_id,
+                format!(
+                    "{description} Try to send another name or use /skip.",
+                    description = err.description()
+                ),
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    let is_free = match is_short_name_free(&bot, &short_name).await {
+        Ok(is_free) => is_free,
+        Err(error) => {
+            error!(
+                ?error,
+                "Error occurred while checking sticker set short name: "
+            );
+
+            send_default_error_message(&bot, chat_id).await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    if !is_free {
+        let mut free_alternatives = Vec::new();
+        for alternative in sticker_set_short_name_alternatives(&short_name, &bot_username) {
+            if free_alternatives.len() == 3 {
+                break;
+            }
+            if let Ok(true) = is_short_name_free(&bot, &alternative).await {
+                free_alternatives.push(html_code(alternative));
+            }
+        }
+
+        let text = if free_alternatives.is_empty() {
+            "This name is already occupied. Try to send another name or use /skip.".to_owned()
+        } else {
+            format!(
+                "This name is already occupied. You can send me one of these names: {alternatives}, \
+                another name or use /skip.",
+                alternatives = free_alternatives.join(", ")
+            )
+        };
+
+        bot.send(SendMessage::new(chat_id, text).parse_mode(ParseMode::HTML))
+            .await?;
+
+        return Ok(EventReturn::Finish);
+    }
+
+    // only panic if bot using in channels, but i'm using private filter in launch function
+    let user_id = message.from.expect("user without id").id;
+
+    create_new_sticker_set(
+        &bot,
+        &fsm,
+        &job_queue,
+        &uow_factory,
+        chat_id,
+        user_id,
+        Some(short_name),
+    )
+    .await
+}
+
+/// ### Panics
+/// - Panics if user is unknown (only if message sent in channel)
+pub async fn skip_short_name<S, UoWFactory>(
+    Extension(bot): Extension<Scheduler>,
+    message: MessageText,
+    fsm: Context<S>,
+    Extension(job_queue): Extension<JobQueue>,
+    Extension(uow_factory): Extension<UoWFactory>,
+) -> HandlerResult
+where
+    S: Storage,
+    UoWFactory: UoWFactoryTrait,
+{
+    // only panic if bot using in channels, but i'm using private filter in launch function
+    let user_id = message.from.expect("user without id").id;
+
+    create_new_sticker_set(
+        &bot,
+        &fsm,
+        &job_queue,
+        &uow_factory,
+        message.chat.id(),
+        user_id,
+        None,
+    )
+    .await
+}
+
+/// Returns `false` if there is a sticker set with `short_name`. Telegram returns the same error for the names
+/// of deleted sticker sets, that are still reserved, so `true` doesn't guarantee that the name is free:
+/// the steal job tries similar names and then a random one, if the sticker set can't be created with it
+async fn is_short_name_free(bot: &Scheduler, short_name: &str) -> Result<bool, SessionErrorKind> {
+    match bot.send(GetStickerSet::new(short_name)).await {
+        Ok(_) => Ok(false),
+        Err(ErrorKind::Telegram(TelegramErrorKind::BadRequest { message }))
+            if message.as_ref() == "Bad Request: STICKERSET_INVALID" =>
+        {
+            Ok(true)
+        }
+        Err(error) => Err(error),
+    }
+}
+
+/// Put the steal job into the queue. If `short_name` is `None`, a random one will be generated.
+async fn create_new_sticker_set<S, UoWFactory>(
+    bot: &Scheduler,
+    fsm: &Context<S>,
+    job_queue: &JobQueue,
+    uow_factory: &UoWFactory,
+    chat_id: i64,
+    user_id: i64,
+    short_name: Option<String>,
+) -> HandlerResult
+where
+    S: Storage,
+    UoWFactory: UoWFactoryTrait,
+{
+    // only panic if i'm forget call fsm.set_value() in function steal_sticker_set_name()
+    let steal_sticker_set_name: Box<str> = fsm
+        .get_value("steal_sticker_set_name")
+        .await
+        .map_err(Into::into)?
+        .expect("Sticker set name for sticker set user want steal should be set");
+    let stickers: Vec<Sticker> = fsm
+        .get_value("steal_stickers")
+        .await
+        .map_err(Into::into)?
+        .expect("Stickers of the sticker set user want steal should be set");
+    let selected: Vec<bool> = fsm
+        .get_value("steal_selected_stickers")
+        .await
+        .map_err(Into::into)?
+        .expect("Selected stickers should be set");
+    let new_set_title: Box<str> = fsm
+        .get_value("steal_new_sticker_set_title")
+        .await
+        .map_err(Into::into)?
+        .expect("Title of the new sticker set should be set");
+
+    fsm.finish().await.map_err(Into::into)?;
+
+    let steal_sticker_set = match bot
+        .send(GetStickerSet::new(steal_sticker_set_name.as_ref()))
+        .await
+    {
+        Ok(steal_sticker_set) => steal_sticker_set,
+        Err(ErrorKind::Telegram(TelegramErrorKind::BadRequest { message }))
+            if message.as_ref() == "Bad Request: STICKERSET_INVALID" =>
+        {
+            bot.send(SendMessage::new(
+                chat_id,
+                "The sticker pack was deleted while you were choosing the stickers, so I can't steal it.",
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+        Err(error) => return Err(error.into()),
+    };
+
+    let sticker_type = stolen_sticker_type(&steal_sticker_set.sticker_type);
+    let in_one_go_limit = create_set_in_one_go_length_limit(sticker_type);
+
+    // cant panic because bot cant be without username
+    let bot_username = bot
+        .send(GetMe::new())
+        .await?
+        .username
+        .expect("bot without username :/");
+
+    let job = StealStickerSet {
+        tg_id: user_id,
+        source_short_name: steal_sticker_set_name.to_string(),
+        source_title: steal_sticker_set.title.to_string(),
+        short_name,
+        thumbnail: steal_sticker_set.thumbnail,
+        title: new_set_title.to_string(),
+        sticker_type: sticker_type.to_owned(),
+        stickers: stickers
+            .into_iter()
+            .zip(selected)
+            .filter_map(|(sticker, is_selected)| is_selected.then_some(sticker))
+            .collect(),
+    };
+
+    let position = match job_queue
+        .try_enqueue_steal_sticker_set(uow_factory, &bot_username, job)
+        .await
+    {
+        Ok(position) => position,
+        Err(err) => {
+            error!(?err, "Error occurred while enqueueing steal job: ");
+
+            bot.send(SendMessage::new(
+                chat_id,
+                "Sorry, I'm too busy right now. Please try again in a few minutes.",
+            ))
+            .await?;
+
+            return Ok(EventReturn::Finish);
+        }
+    };
+
+    bot.send(SendMessage::new(
+        chat_id,
+        format!(
+            "Stealing sticker pack with name {title} for you.. Your place in the queue: {position}. \
+            It may take up to a several minutes, if this sticker pack containing more than {in_one_go_limit} stickers. \
+            I'll send you a message when it's ready.",
+            title = html_code(html_quote(&new_set_title)),
+            position = html_code(position.to_string())
+        ),
+    ).parse_mode(ParseMode::HTML))
+    .await?;
+
+    Ok(EventReturn::Finish)
+}
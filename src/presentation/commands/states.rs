@@ -1,4 +1,14 @@
 pub mod add_stickers;
 pub mod callback_data;
+pub mod claim_sticker_set;
+pub mod delete_sticker_set;
+pub mod export_sticker_set;
+pub mod follow_source;
 pub mod get_owner;
+pub mod merge_sticker_sets;
+pub mod new_sticker_set;
+pub mod remove_sticker;
+pub mod rename_sticker_set;
+pub mod reorder_stickers;
+pub mod set_emoji;
 pub mod steal_sticker_set;
@@ -3,6 +3,12 @@ use CallbackDataPrefix::*;
 pub enum CallbackDataPrefix {
     MyStickers,
     Stats,
+    SelectStickers,
+    ExistingCopy,
+    AddEntirePack,
+    DeletePack,
+    Reorder,
+    SetEmoji,
 }
 
 impl CallbackDataPrefix {
@@ -10,6 +16,12 @@ impl CallbackDataPrefix {
         match self {
             MyStickers => "MyStickers",
             Stats => "Stats",
+            SelectStickers => "SelectStickers",
+            ExistingCopy => "ExistingCopy",
+            AddEntirePack => "AddEntirePack",
+            DeletePack => "DeletePack",
+            Reorder => "Reorder",
+            SetEmoji => "SetEmoji",
         }
     }
 }
@@ -0,0 +1,26 @@
+use std::borrow::Cow;
+
+#[derive(Clone)]
+pub enum ClaimStickerSetState {
+    GetStickerSet,
+}
+
+impl ClaimStickerSetState {
+    const fn as_str(&self) -> &'static str {
+        match self {
+            ClaimStickerSetState::GetStickerSet => "get_sticker_set_to_claim",
+        }
+    }
+}
+
+impl From<ClaimStickerSetState> for Cow<'static, str> {
+    fn from(state: ClaimStickerSetState) -> Self {
+        Cow::Borrowed(state.as_str())
+    }
+}
+
+impl PartialEq<&str> for ClaimStickerSetState {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
@@ -0,0 +1,28 @@
+use std::borrow::Cow;
+
+#[derive(Clone)]
+pub enum DeleteStickerSetState {
+    SelectStickerSet,
+    Confirm,
+}
+
+impl DeleteStickerSetState {
+    const fn as_str(&self) -> &'static str {
+        match self {
+            DeleteStickerSetState::SelectStickerSet => "select_sticker_set_to_delete",
+            DeleteStickerSetState::Confirm => "confirm_sticker_set_deletion",
+        }
+    }
+}
+
+impl From<DeleteStickerSetState> for Cow<'static, str> {
+    fn from(state: DeleteStickerSetState) -> Self {
+        Cow::Borrowed(state.as_str())
+    }
+}
+
+impl PartialEq<&str> for DeleteStickerSetState {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
@@ -0,0 +1,26 @@
+use std::borrow::Cow;
+
+#[derive(Clone)]
+pub enum ExportStickerSetState {
+    GetStickerSet,
+}
+
+impl ExportStickerSetState {
+    const fn as_str(&self) -> &'static str {
+        match self {
+            ExportStickerSetState::GetStickerSet => "get_sticker_set_to_export",
+        }
+    }
+}
+
+impl From<ExportStickerSetState> for Cow<'static, str> {
+    fn from(state: ExportStickerSetState) -> Self {
+        Cow::Borrowed(state.as_str())
+    }
+}
+
+impl PartialEq<&str> for ExportStickerSetState {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
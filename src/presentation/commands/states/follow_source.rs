@@ -0,0 +1,26 @@
+use std::borrow::Cow;
+
+#[derive(Clone)]
+pub enum FollowSourceState {
+    GetStolenStickerSet,
+}
+
+impl FollowSourceState {
+    const fn as_str(&self) -> &'static str {
+        match self {
+            FollowSourceState::GetStolenStickerSet => "get_stolen_sticker_set_to_follow",
+        }
+    }
+}
+
+impl From<FollowSourceState> for Cow<'static, str> {
+    fn from(state: FollowSourceState) -> Self {
+        Cow::Borrowed(state.as_str())
+    }
+}
+
+impl PartialEq<&str> for FollowSourceState {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
@@ -0,0 +1,28 @@
+use std::borrow::Cow;
+
+#[derive(Clone)]
+pub enum MergeStickerSetsState {
+    GetStickerSetsToMerge,
+    CreateMergedStickerSet,
+}
+
+impl MergeStickerSetsState {
+    const fn as_str(&self) -> &'static str {
+        match self {
+            MergeStickerSetsState::GetStickerSetsToMerge => "get_sticker_sets_to_merge",
+            MergeStickerSetsState::CreateMergedStickerSet => "create_merged_sticker_set",
+        }
+    }
+}
+
+impl From<MergeStickerSetsState> for Cow<'static, str> {
+    fn from(state: MergeStickerSetsState) -> Self {
+        Cow::Borrowed(state.as_str())
+    }
+}
+
+impl PartialEq<&str> for MergeStickerSetsState {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
@@ -0,0 +1,28 @@
+use std::borrow::Cow;
+
+#[derive(Clone)]
+pub enum NewStickerSetState {
+    GetImages,
+    GetTitle,
+}
+
+impl NewStickerSetState {
+    const fn as_str(&self) -> &'static str {
+        match self {
+            NewStickerSetState::GetImages => "get_images_for_new_sticker_set",
+            NewStickerSetState::GetTitle => "get_new_sticker_set_title_from_images",
+        }
+    }
+}
+
+impl From<NewStickerSetState> for Cow<'static, str> {
+    fn from(state: NewStickerSetState) -> Self {
+        Cow::Borrowed(state.as_str())
+    }
+}
+
+impl PartialEq<&str> for NewStickerSetState {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
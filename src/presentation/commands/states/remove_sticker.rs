@@ -0,0 +1,26 @@
+use std::borrow::Cow;
+
+#[derive(Clone)]
+pub enum RemoveStickerState {
+    GetStickersToRemove,
+}
+
+impl RemoveStickerState {
+    const fn as_str(&self) -> &'static str {
+        match self {
+            RemoveStickerState::GetStickersToRemove => "get_stickers_to_remove",
+        }
+    }
+}
+
+impl From<RemoveStickerState> for Cow<'static, str> {
+    fn from(state: RemoveStickerState) -> Self {
+        Cow::Borrowed(state.as_str())
+    }
+}
+
+impl PartialEq<&str> for RemoveStickerState {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
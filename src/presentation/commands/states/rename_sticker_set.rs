@@ -0,0 +1,28 @@
+use std::borrow::Cow;
+
+#[derive(Clone)]
+pub enum RenameStickerSetState {
+    GetStickerSet,
+    GetNewTitle,
+}
+
+impl RenameStickerSetState {
+    const fn as_str(&self) -> &'static str {
+        match self {
+            RenameStickerSetState::GetStickerSet => "get_sticker_set_to_rename",
+            RenameStickerSetState::GetNewTitle => "get_new_sticker_set_title",
+        }
+    }
+}
+
+impl From<RenameStickerSetState> for Cow<'static, str> {
+    fn from(state: RenameStickerSetState) -> Self {
+        Cow::Borrowed(state.as_str())
+    }
+}
+
+impl PartialEq<&str> for RenameStickerSetState {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
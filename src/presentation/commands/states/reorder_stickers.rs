@@ -0,0 +1,28 @@
+use std::borrow::Cow;
+
+#[derive(Clone)]
+pub enum ReorderStickersState {
+    GetSticker,
+    GetPosition,
+}
+
+impl ReorderStickersState {
+    const fn as_str(&self) -> &'static str {
+        match self {
+            ReorderStickersState::GetSticker => "get_sticker_to_reorder",
+            ReorderStickersState::GetPosition => "get_new_sticker_position",
+        }
+    }
+}
+
+impl From<ReorderStickersState> for Cow<'static, str> {
+    fn from(state: ReorderStickersState) -> Self {
+        Cow::Borrowed(state.as_str())
+    }
+}
+
+impl PartialEq<&str> for ReorderStickersState {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
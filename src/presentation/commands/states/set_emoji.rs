@@ -0,0 +1,28 @@
+use std::borrow::Cow;
+
+#[derive(Clone)]
+pub enum SetEmojiState {
+    GetSticker,
+    GetEmoji,
+}
+
+impl SetEmojiState {
+    const fn as_str(&self) -> &'static str {
+        match self {
+            SetEmojiState::GetSticker => "get_sticker_to_set_emoji",
+            SetEmojiState::GetEmoji => "get_new_sticker_emoji",
+        }
+    }
+}
+
+impl From<SetEmojiState> for Cow<'static, str> {
+    fn from(state: SetEmojiState) -> Self {
+        Cow::Borrowed(state.as_str())
+    }
+}
+
+impl PartialEq<&str> for SetEmojiState {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
@@ -3,14 +3,20 @@ use std::borrow::Cow;
 #[derive(Clone)]
 pub enum StealStickerSetState {
     StealStickerSetName,
+    ExistingCopy,
+    SelectStickers,
     CreateNewStickerSet,
+    GetShortName,
 }
 
 impl StealStickerSetState {
     const fn as_str(&self) -> &'static str {
         match self {
             StealStickerSetState::StealStickerSetName => "steal_sticker_set_name",
+            StealStickerSetState::ExistingCopy => "steal_existing_copy_action",
+            StealStickerSetState::SelectStickers => "select_stickers_to_steal",
             StealStickerSetState::CreateNewStickerSet => "create_new_sticker_set",
+            StealStickerSetState::GetShortName => "get_new_sticker_set_short_name",
         }
     }
 }
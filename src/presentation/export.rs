@@ -0,0 +1,234 @@
+use std::io::{Cursor, Write as _};
+
+use async_trait::async_trait;
+use serde::Serialize;
+use telers::{errors::SessionErrorKind, methods::GetFile, types::StickerSet};
+use zip::{CompressionMethod, ZipWriter, result::ZipError, write::SimpleFileOptions};
+
+use crate::{
+    domain::entities::sticker::StickerFormat,
+    presentation::scheduler::{DownloadError, Scheduler},
+};
+
+/// Source of the sticker files, it's Telegram for the bot and a local directory for the tests
+#[async_trait]
+pub trait FileFetcher {
+    /// Get content of the file with `file_id`
+    async fn fetch(&self, file_id: &str) -> Result<Vec<u8>, FetchError>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum FetchError {
+    #[error(transparent)]
+    Telegram(#[from] SessionErrorKind),
+    #[error(transparent)]
+    Download(#[from] DownloadError),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("file {file_id} has no path")]
+    NoPath { file_id: String },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    #[error("error occurred while fetching sticker file: {0}")]
+    Fetch(#[from] FetchError),
+    #[error(transparent)]
+    Zip(#[from] ZipError),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+}
+
+#[async_trait]
+impl FileFetcher for Scheduler {
+    async fn fetch(&self, file_id: &str) -> Result<Vec<u8>, FetchError> {
+        let file = self.send(GetFile::new(file_id)).await?;
+
+        match file.file_path.as_deref() {
+            Some(file_path) => Ok(self.download_file(file_path).await?),
+            None => Err(FetchError::NoPath {
+                file_id: file_id.to_owned(),
+            }),
+        }
+    }
+}
+
+/// Files are read from the directory by their ids: `<dir>/<file_id>`
+#[cfg(test)]
+pub struct DirFileFetcher {
+    pub dir: std::path::PathBuf,
+}
+
+#[cfg(test)]
+#[async_trait]
+impl FileFetcher for DirFileFetcher {
+    async fn fetch(&self, file_id: &str) -> Result<Vec<u8>, FetchError> {
+        Ok(std::fs::read(self.dir.join(file_id))?)
+    }
+}
+
+/// Content of `pack.json` in the archive
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PackManifest<'a> {
+    pub name: &'a str,
+    pub title: &'a str,
+    /// `regular`, `mask` or `custom_emoji`
+    pub sticker_type: &'a str,
+    /// Stickers in the order of the sticker set
+    pub stickers: Vec<ManifestSticker<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ManifestSticker<'a> {
+    /// Position of the sticker in the sticker set, starts from 1
+    pub position: usize,
+    /// Name of the sticker file in the archive
+    pub file: String,
+    pub emoji: Option<&'a str>,
+    /// `static`, `animated` or `video`
+    pub format: &'static str,
+}
+
+/// Extension of the sticker files with `format`
+const fn sticker_file_extension(format: StickerFormat) -> &'static str {
+    match format {
+        StickerFormat::Static => "webp",
+        StickerFormat::Animated => "tgs",
+        StickerFormat::Video => "webm",
+    }
+}
+
+/// Download all stickers of the `sticker_set` and pack them into ZIP archive
+/// with `pack.json` manifest, that keeps the title, emoji, formats and order of the stickers
+pub async fn export_sticker_set<F>(
+    fetcher: &F,
+    sticker_set: &StickerSet,
+) -> Result<Vec<u8>, ExportError>
+where
+    F: FileFetcher + Sync,
+{
+    let mut archive = ZipWriter::new(Cursor::new(Vec::new()));
+    // sticker files are already compressed
+    let stored = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+
+    let mut manifest = PackManifest {
+        name: &sticker_set.name,
+        title: &sticker_set.title,
+        sticker_type: &sticker_set.sticker_type,
+        stickers: Vec::with_capacity(sticker_set.stickers.len()),
+    };
+
+    for (index, sticker) in sticker_set.stickers.iter().enumerate() {
+        let format = StickerFormat::from(sticker);
+        let file = format!(
+            "{position:03}.{extension}",
+            position = index + 1,
+            extension = sticker_file_extension(format)
+        );
+
+        let content = fetcher.fetch(&sticker.file_id).await?;
+        archive.start_file(file.as_str(), stored)?;
+        archive.write_all(&content)?;
+
+        manifest.stickers.push(ManifestSticker {
+            position: index + 1,
+            file,
+            emoji: sticker.emoji.as_deref(),
+            format: format.as_str(),
+        });
+    }
+
+    archive.start_file("pack.json", SimpleFileOptions::default())?;
+    archive.write_all(&serde_json::to_vec_pretty(&manifest)?)?;
+
+    Ok(archive.finish()?.into_inner())
+}
+
+#[tokio::test]
+async fn export_sticker_set_test() {
+    use std::io::Read as _;
+
+    let fixtures = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let fetcher = DirFileFetcher {
+        dir: fixtures.clone(),
+    };
+
+    let sticker_set: StickerSet = serde_json::from_str(
+        r#"{
+            "name": "test_by_bot",
+            "title": "Test pack",
+            "sticker_type": "regular",
+            "stickers": [
+                {
+                    "file_id": "small.webp",
+                    "file_unique_id": "small",
+                    "type": "regular",
+                    "width": 512,
+                    "height": 512,
+                    "is_animated": false,
+                    "is_video": false,
+                    "emoji": "😎"
+                },
+                {
+                    "file_id": "landscape.png",
+                    "file_unique_id": "landscape",
+                    "type": "regular",
+                    "width": 512,
+                    "height": 341,
+                    "is_animated": false,
+                    "is_video": true,
+                    "emoji": "🐱"
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    let archive = export_sticker_set(&fetcher, &sticker_set).await.unwrap();
+    let mut archive = zip::ZipArchive::new(Cursor::new(archive)).unwrap();
+
+    let mut names: Vec<&str> = archive.file_names().collect();
+    names.sort_unstable();
+    assert_eq!(names, ["001.webp", "002.webm", "pack.json"]);
+
+    for (name, fixture) in [("001.webp", "small.webp"), ("002.webm", "landscape.png")] {
+        let mut content = Vec::new();
+        archive
+            .by_name(name)
+            .unwrap()
+            .read_to_end(&mut content)
+            .unwrap();
+        assert_eq!(content, std::fs::read(fixtures.join(fixture)).unwrap());
+    }
+
+    let mut manifest = String::new();
+    archive
+        .by_name("pack.json")
+        .unwrap()
+        .read_to_string(&mut manifest)
+        .unwrap();
+    let manifest: serde_json::Value = serde_json::from_str(&manifest).unwrap();
+    assert_eq!(
+        manifest,
+        serde_json::json!({
+            "name": "test_by_bot",
+            "title": "Test pack",
+            "sticker_type": "regular",
+            "stickers": [
+                { "position": 1, "file": "001.webp", "emoji": "😎", "format": "static" },
+                { "position": 2, "file": "002.webm", "emoji": "🐱", "format": "video" }
+            ]
+        })
+    );
+
+    // missing files fail the whole export
+    let fetcher = DirFileFetcher {
+        dir: fixtures.join("missing"),
+    };
+    assert!(matches!(
+        export_sticker_set(&fetcher, &sticker_set).await,
+        Err(ExportError::Fetch(FetchError::Io(_)))
+    ));
+}
@@ -0,0 +1,14 @@
+mod add_stickers;
+mod common;
+mod export_sticker_set;
+mod queue;
+mod set_sticker_set_emoji;
+mod steal_sticker_set;
+mod workers;
+
+pub use add_stickers::AddStickers;
+pub use export_sticker_set::ExportStickerSet;
+pub use queue::{EnqueueError, Job, JobQueue, JobReceiver, job_queue};
+pub use set_sticker_set_emoji::SetStickerSetEmoji;
+pub use steal_sticker_set::StealStickerSet;
+pub use workers::run_workers;
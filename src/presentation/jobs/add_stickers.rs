@@ -0,0 +1,107 @@
+use telers::{
+    enums::ParseMode,
+    errors::HandlerError,
+    methods::SendMessage,
+    types::Sticker,
+    utils::text::{html_quote, html_text_link},
+};
+
+use super::{common::add_job_stickers, queue::EnqueueError};
+use crate::{
+    application::{
+        common::traits::uow::UoWFactory as UoWFactoryTrait,
+        interactors::create_steal_job::create_steal_job,
+        steal_job::dto::create::Create as CreateStealJob,
+    },
+    core::helpers::constants::TELEGRAM_STICKER_SET_URL,
+    domain::entities::steal_job::{StealJob, StealJobKind},
+    presentation::scheduler::Scheduler,
+};
+
+#[derive(Debug, Clone)]
+pub struct AddStickers {
+    pub tg_id: i64,
+    pub short_name: String,
+    pub title: String,
+    /// Type of the user's sticker set: `regular`, `mask` or `custom_emoji`
+    pub sticker_type: String,
+    pub stickers: Vec<Sticker>,
+}
+
+/// Save the job, so that it can be resumed if the bot is restarted before it's finished, even if it didn't start
+pub(super) async fn save_add_stickers<UoWFactory>(
+    uow_factory: &UoWFactory,
+    job: AddStickers,
+) -> Result<StealJob, EnqueueError>
+where
+    UoWFactory: UoWFactoryTrait,
+{
+    let mut uow = uow_factory.create_uow();
+
+    let id = create_steal_job(
+        &mut uow,
+        CreateStealJob::new(
+            StealJobKind::AddStickers,
+            job.tg_id,
+            None,
+            None,
+            job.short_name.as_str(),
+            job.title.as_str(),
+            job.sticker_type.as_str(),
+            None,
+            &job.stickers,
+        ),
+    )
+    .await
+    .map_err(|err| EnqueueError::new(err.to_string()))?;
+
+    match id {
+        Some(id) => Ok(StealJob {
+            id,
+            kind: StealJobKind::AddStickers,
+            tg_id: job.tg_id,
+            source_short_name: None,
+            source_title: None,
+            short_name: job.short_name,
+            title: job.title,
+            sticker_type: job.sticker_type,
+            thumbnail: None,
+            stickers: job.stickers,
+            processed: 0,
+            failed: 0,
+            finished: false,
+        }),
+        None => Err(EnqueueError::new("add stickers job could not be saved")),
+    }
+}
+
+/// Add all the stickers of the saved job, that have not been processed yet, into the user's sticker set
+/// and notify the user when it's done
+pub(super) async fn add_stickers<UoWFactory>(
+    bot: &Scheduler,
+    uow_factory: &UoWFactory,
+    job: StealJob,
+) -> Result<(), HandlerError>
+where
+    UoWFactory: UoWFactoryTrait,
+{
+    let failed = add_job_stickers(bot, uow_factory, &job).await?;
+
+    let set = html_text_link(
+        html_quote(&job.title),
+        format!("{TELEGRAM_STICKER_SET_URL}{}", job.short_name),
+    );
+
+    let stickers_was_added_msg = if failed == 0 {
+        format!("Sticker(s) have been added into {set}!")
+    } else {
+        format!(
+            "Error occurred while adding stickers into {set}. Due to an error, not all specified stickers have been added."
+        )
+    };
+
+    bot.send(SendMessage::new(job.tg_id, stickers_was_added_msg).parse_mode(ParseMode::HTML))
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use telers::{
+    errors::{HandlerError, SessionErrorKind},
+    methods::AddStickerToSet,
+    types::Sticker,
+};
+use tracing::error;
+
+use crate::{
+    application::{
+        common::{exceptions::TransactionKind, traits::uow::UoWFactory as UoWFactoryTrait},
+        interactors::{
+            update_steal_job_progress::update_steal_job_progress,
+            update_sticker_count::update_sticker_count,
+        },
+        set::dto::update_sticker_count::UpdateStickerCount,
+        steal_job::dto::update_progress::UpdateProgress,
+    },
+    core::helpers::common::input_sticker,
+    domain::entities::steal_job::StealJob,
+    presentation::scheduler::Scheduler,
+};
+
+/// Add one sticker to the sticker set `set_name` with `set_type` owned by user `user_id`.
+pub(super) async fn add_sticker(
+    bot: &Scheduler,
+    user_id: i64,
+    set_name: &str,
+    set_type: &str,
+    sticker: &Sticker,
+) -> Result<(), SessionErrorKind> {
+    bot.send(AddStickerToSet::new(
+        user_id,
+        set_name,
+        input_sticker(sticker, set_type),
+    ))
+    .await
+    .map(|_| ())
+}
+
+/// Add all the stickers of the job that have not been processed yet into its sticker set,
+/// saving the progress and the number of stickers of the set after each sticker.
+/// Returns the number of stickers that could not be added.
+pub(super) async fn add_job_stickers<UoWFactory>(
+    bot: &Scheduler,
+    uow_factory: &UoWFactory,
+    job: &StealJob,
+) -> Result<i32, HandlerError>
+where
+    UoWFactory: UoWFactoryTrait,
+{
+    let mut uow = uow_factory.create_uow();
+
+    let stickers_len = job.stickers.len() as i32;
+    let (mut processed, mut failed) = (job.processed, job.failed);
+
+    for sticker in job.stickers.iter().skip(processed as usize) {
+        if let Err(err) =
+            add_sticker(bot, job.tg_id, &job.short_name, &job.sticker_type, sticker).await
+        {
+            error!(
+                ?err,
+                ?job.short_name,
+                "Error occurred while adding stickers to sticker set: "
+            );
+            failed += 1;
+        } else {
+            update_sticker_count(&mut uow, UpdateStickerCount::new(&job.short_name, 1))
+                .await
+                .map_err(HandlerError::new)?;
+        }
+        processed += 1;
+
+        update_steal_job_progress(
+            &mut uow,
+            UpdateProgress::new(job.id, processed, failed, processed == stickers_len),
+        )
+        .await
+        .map_err(HandlerError::new)?;
+    }
+
+    Ok(failed)
+}
+
+/// Mark the saved `jobs` as finished without processing their stickers,
+/// so that they are not resumed after the user was notified about the error
+pub(super) async fn cancel_steal_jobs<UoWFactory>(
+    uow_factory: &UoWFactory,
+    jobs: &[StealJob],
+) -> Result<(), TransactionKind>
+where
+    UoWFactory: UoWFactoryTrait,
+{
+    let mut uow = uow_factory.create_uow();
+
+    for job in jobs {
+        let stickers_len = job.stickers.len() as i32;
+
+        update_steal_job_progress(
+            &mut uow,
+            UpdateProgress::new(job.id, stickers_len, stickers_len, true),
+        )
+        .await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use telers::{
+    enums::ParseMode,
+    errors::HandlerError,
+    methods::{SendDocument, SendMessage},
+    types::{InputFile, StickerSet},
+    utils::text::{html_quote, html_text_link},
+};
+use tracing::error;
+
+use crate::{
+    core::helpers::{constants::TELEGRAM_STICKER_SET_URL, texts::default_error_message},
+    presentation::{export, scheduler::Scheduler},
+};
+
+#[derive(Debug, Clone)]
+pub struct ExportStickerSet {
+    /// Chat to send the archive to
+    pub chat_id: i64,
+    pub sticker_set: StickerSet,
+}
+
+/// Download all stickers of the sticker set and send them to the user as ZIP archive
+pub(super) async fn export_sticker_set(
+    bot: &Scheduler,
+    job: ExportStickerSet,
+) -> Result<(), HandlerError> {
+    let set_name = &*job.sticker_set.name;
+
+    let archive = match export::export_sticker_set(bot, &job.sticker_set).await {
+        Ok(archive) => archive,
+        Err(err) => {
+            error!(
+                ?err,
+                ?set_name,
+                "Error occurred while exporting sticker set: "
+            );
+
+            bot.send(SendMessage::new(job.chat_id, default_error_message()))
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let link = html_text_link(
+        html_quote(&job.sticker_set.title),
+        format!("{TELEGRAM_STICKER_SET_URL}{set_name}"),
+    );
+
+    bot.send(
+        SendDocument::new(
+            job.chat_id,
+            InputFile::buffered_with_name(archive, format!("{set_name}.zip")),
+        )
+        .caption(format!(
+            "Here is {link}! Stickers are in the order of the sticker pack, \
+            their emoji and formats are in pack.json."
+        ))
+        .parse_mode(ParseMode::HTML),
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,200 @@
+use std::{
+    borrow::Cow,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
+
+use tokio::sync::{
+    Mutex,
+    mpsc::{self, error::TrySendError},
+};
+
+use super::{
+    add_stickers::{AddStickers, save_add_stickers},
+    common::cancel_steal_jobs,
+    export_sticker_set::ExportStickerSet,
+    set_sticker_set_emoji::SetStickerSetEmoji,
+    steal_sticker_set::{StealStickerSet, save_steal_sticker_set},
+};
+use crate::{
+    application::common::traits::uow::UoWFactory as UoWFactoryTrait,
+    domain::entities::steal_job::StealJob,
+};
+
+#[derive(Debug, Clone)]
+pub enum Job {
+    /// Create new sticker sets from the saved parts of the steal job
+    StealStickerSet(Vec<StealJob>),
+    /// Add the remaining stickers of the unfinished steal job
+    ResumeStealJob(StealJob),
+    /// Add the remaining stickers of the saved job into the existing user's sticker set
+    AddStickers(StealJob),
+    /// Send the sticker set to the user as ZIP archive
+    ExportStickerSet(ExportStickerSet),
+    /// Set the same emoji to all stickers of the sticker set
+    SetStickerSetEmoji(SetStickerSetEmoji),
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("Error occurred while enqueueing job: {message}")]
+pub struct EnqueueError {
+    message: Cow<'static, str>,
+}
+
+impl EnqueueError {
+    pub(super) fn new(message: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+/// Sending half of the job queue, can be used by any handler to put jobs for the workers
+#[derive(Debug, Clone)]
+pub struct JobQueue {
+    sender: mpsc::Sender<Job>,
+    /// Number of jobs that are waiting in the queue
+    queued: Arc<AtomicUsize>,
+}
+
+/// Receiving half of the job queue, shared between all workers
+#[derive(Debug, Clone)]
+pub struct JobReceiver {
+    receiver: Arc<Mutex<mpsc::Receiver<Job>>>,
+    queued: Arc<AtomicUsize>,
+}
+
+/// Create a new job queue that can contain up to `capacity` waiting jobs
+pub fn job_queue(capacity: usize) -> (JobQueue, JobReceiver) {
+    let (sender, receiver) = mpsc::channel(capacity);
+    let queued = Arc::new(AtomicUsize::new(0));
+
+    (
+        JobQueue {
+            sender,
+            queued: queued.clone(),
+        },
+        JobReceiver {
+            receiver: Arc::new(Mutex::new(receiver)),
+            queued,
+        },
+    )
+}
+
+impl JobQueue {
+    /// Put the job at the end of the queue without waiting. Returns position of the job
+    /// in the queue (starting from 1) or error, if the queue is full.
+    pub fn try_enqueue(&self, job: Job) -> Result<usize, EnqueueError> {
+        let position = self.queued.fetch_add(1, Ordering::SeqCst) + 1;
+
+        if let Err(err) = self.sender.try_send(job) {
+            self.queued.fetch_sub(1, Ordering::SeqCst);
+
+            return Err(match err {
+                TrySendError::Full(_) => EnqueueError::new("queue is full"),
+                TrySendError::Closed(_) => EnqueueError::new("queue is closed"),
+            });
+        }
+
+        Ok(position)
+    }
+
+    /// Put the job at the end of the queue, waiting for free space if the queue is full.
+    /// Returns position of the job in the queue (starting from 1).
+    pub async fn enqueue(&self, job: Job) -> Result<usize, EnqueueError> {
+        let position = self.queued.fetch_add(1, Ordering::SeqCst) + 1;
+
+        if self.sender.send(job).await.is_err() {
+            self.queued.fetch_sub(1, Ordering::SeqCst);
+
+            return Err(EnqueueError::new("queue is closed"));
+        }
+
+        Ok(position)
+    }
+
+    /// Save the steal job, so that it's resumed if the bot is restarted, and put it at the end of the queue
+    /// without waiting. Returns position of the job in the queue (starting from 1) or error,
+    /// if the job could not be saved or the queue is full.
+    pub async fn try_enqueue_steal_sticker_set<UoWFactory>(
+        &self,
+        uow_factory: &UoWFactory,
+        bot_username: &str,
+        job: StealStickerSet,
+    ) -> Result<usize, EnqueueError>
+    where
+        UoWFactory: UoWFactoryTrait,
+    {
+        let parts = save_steal_sticker_set(uow_factory, bot_username, job).await?;
+        let result = self.try_enqueue(Job::StealStickerSet(parts.clone()));
+
+        cancel_if_not_enqueued(uow_factory, &parts, result).await
+    }
+
+    /// Save the job, so that it's resumed if the bot is restarted, and put it at the end of the queue
+    /// without waiting. Returns position of the job in the queue (starting from 1) or error,
+    /// if the job could not be saved or the queue is full.
+    pub async fn try_enqueue_add_stickers<UoWFactory>(
+        &self,
+        uow_factory: &UoWFactory,
+        job: AddStickers,
+    ) -> Result<usize, EnqueueError>
+    where
+        UoWFactory: UoWFactoryTrait,
+    {
+        let job = save_add_stickers(uow_factory, job).await?;
+        let result = self.try_enqueue(Job::AddStickers(job.clone()));
+
+        cancel_if_not_enqueued(uow_factory, &[job], result).await
+    }
+
+    /// Save the job, so that it's resumed if the bot is restarted, and put it at the end of the queue,
+    /// waiting for free space if the queue is full. Returns position of the job in the queue (starting from 1).
+    pub async fn enqueue_add_stickers<UoWFactory>(
+        &self,
+        uow_factory: &UoWFactory,
+        job: AddStickers,
+    ) -> Result<usize, EnqueueError>
+    where
+        UoWFactory: UoWFactoryTrait,
+    {
+        let job = save_add_stickers(uow_factory, job).await?;
+
+        // the queue is closed only when the bot is stopping, so the saved job is resumed after restart
+        self.enqueue(Job::AddStickers(job)).await
+    }
+}
+
+/// The user is asked to try again later, if the saved `jobs` were not put into the queue,
+/// so they shouldn't be resumed after restart
+async fn cancel_if_not_enqueued<UoWFactory>(
+    uow_factory: &UoWFactory,
+    jobs: &[StealJob],
+    result: Result<usize, EnqueueError>,
+) -> Result<usize, EnqueueError>
+where
+    UoWFactory: UoWFactoryTrait,
+{
+    if result.is_err() {
+        cancel_steal_jobs(uow_factory, jobs)
+            .await
+            .map_err(|err| EnqueueError::new(err.to_string()))?;
+    }
+
+    result
+}
+
+impl JobReceiver {
+    /// Wait for the next job. Returns `None` if all the senders were dropped
+    pub async fn recv(&self) -> Option<Job> {
+        let job = self.receiver.lock().await.recv().await;
+
+        if job.is_some() {
+            self.queued.fetch_sub(1, Ordering::SeqCst);
+        }
+
+        job
+    }
+}
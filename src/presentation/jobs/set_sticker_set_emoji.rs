@@ -0,0 +1,65 @@
+use telers::{
+    enums::ParseMode,
+    errors::HandlerError,
+    methods::{SendMessage, SetStickerEmojiList},
+    types::StickerSet,
+    utils::text::{html_code, html_quote, html_text_link},
+};
+use tracing::error;
+
+use crate::{
+    core::helpers::constants::TELEGRAM_STICKER_SET_URL, presentation::scheduler::Scheduler,
+};
+
+#[derive(Debug, Clone)]
+pub struct SetStickerSetEmoji {
+    /// Chat to send the result to
+    pub chat_id: i64,
+    pub sticker_set: StickerSet,
+    pub emoji_list: Vec<String>,
+}
+
+/// Set the emoji of the job to all stickers of the sticker set and notify the user when it's done
+pub(super) async fn set_sticker_set_emoji(
+    bot: &Scheduler,
+    job: SetStickerSetEmoji,
+) -> Result<(), HandlerError> {
+    let set_name = &*job.sticker_set.name;
+
+    let mut failed = 0;
+    for sticker in job.sticker_set.stickers.iter() {
+        if let Err(error) = bot
+            .send(SetStickerEmojiList::new(
+                sticker.file_id.as_ref(),
+                job.emoji_list.clone(),
+            ))
+            .await
+        {
+            error!(
+                ?error,
+                ?set_name,
+                "Error occurred while setting sticker emoji: "
+            );
+            failed += 1;
+        }
+    }
+
+    let mut text = format!(
+        "Done! The emoji of the stickers of {link} were changed.",
+        link = html_text_link(
+            html_quote(&job.sticker_set.title),
+            format!("{TELEGRAM_STICKER_SET_URL}{set_name}")
+        )
+    );
+    if failed > 0 {
+        text.push_str(&format!(
+            " Due to an error, the emoji of {failed} sticker(s) were not changed.",
+            failed = html_code(failed.to_string())
+        ));
+    }
+
+    bot.send(SendMessage::new(job.chat_id, text).parse_mode(ParseMode::HTML))
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,641 @@
+use chrono::Utc;
+use telers::{
+    enums::ParseMode,
+    errors::{HandlerError, SessionErrorKind, TelegramErrorKind, session::ErrorKind},
+    methods::{
+        CreateNewStickerSet, GetFile, GetMe, GetStickerSet, SendMessage,
+        SetCustomEmojiStickerSetThumbnail, SetStickerSetThumbnail,
+    },
+    types::{InputFile, PhotoSize, Sticker},
+    utils::text::{html_code, html_quote, html_text_link},
+};
+use tracing::{debug, error};
+
+use super::{
+    common::{add_job_stickers, cancel_steal_jobs},
+    queue::EnqueueError,
+};
+use crate::{
+    application::{
+        common::{
+            exceptions::TransactionKind,
+            traits::uow::{UoW as _, UoWFactory as UoWFactoryTrait},
+        },
+        interactors::{
+            create_set::create_set, create_steal_job::create_steal_job,
+            update_steal_job_progress::update_steal_job_progress,
+            update_steal_job_short_name::update_steal_job_short_name,
+        },
+        set::{
+            dto::{
+                create::Create as CreateSet, get_by_short_name::GetByShortName as GetSetByShortName,
+            },
+            repository::SetRepo as _,
+        },
+        steal_job::dto::{
+            create::Create as CreateStealJob, update_progress::UpdateProgress,
+            update_short_name::UpdateShortName,
+        },
+    },
+    core::helpers::{
+        common::{
+            create_set_in_one_go_length_limit, generate_sticker_set_name_and_link, input_sticker,
+            is_sticker_set_short_name, max_sticker_set_length, part_title,
+            sticker_set_short_name_alternatives, thumbnail_format,
+        },
+        constants::{CUSTOM_EMOJI_STICKER_TYPE, MASK_STICKER_TYPE, TELEGRAM_STICKER_SET_URL},
+        texts::{provenance_message, split_sticker_sets_message, sticker_set_message},
+    },
+    domain::entities::steal_job::{StealJob, StealJobKind},
+    presentation::scheduler::Scheduler,
+};
+
+#[derive(Debug, Clone)]
+pub struct StealStickerSet {
+    pub tg_id: i64,
+    pub source_short_name: String,
+    pub source_title: String,
+    /// Short name of the new sticker set chosen by user. If it's `None`, a random one will be generated.
+    pub short_name: Option<String>,
+    /// Thumbnail of the source sticker set, that will be copied to the new one
+    pub thumbnail: Option<PhotoSize>,
+    pub title: String,
+    /// Type of the new sticker set: `regular`, `mask` or `custom_emoji`
+    pub sticker_type: String,
+    pub stickers: Vec<Sticker>,
+}
+
+/// Sticker set created by the steal job
+struct CreatedSet {
+    title: String,
+    short_name: String,
+    link: String,
+    /// Number of stickers that could not be added
+    failed: i32,
+    /// Short name chosen for the sticker set, if it was occupied and the sticker set got another one
+    occupied_short_name: Option<String>,
+}
+
+/// Save the steal job, so that it can be resumed if the bot is restarted before it's finished, even if it
+/// didn't start. If there are more stickers than fit into one sticker set, they are split into several numbered
+/// sticker sets: "Title (1/2)", "Title (2/2)" and so on, and a job is saved for each of them.
+pub(super) async fn save_steal_sticker_set<UoWFactory>(
+    uow_factory: &UoWFactory,
+    bot_username: &str,
+    job: StealStickerSet,
+) -> Result<Vec<StealJob>, EnqueueError>
+where
+    UoWFactory: UoWFactoryTrait,
+{
+    let chunks = job
+        .stickers
+        .chunks(max_sticker_set_length(&job.sticker_type));
+    let parts_len = chunks.len();
+
+    let mut parts = Vec::with_capacity(parts_len);
+    for (index, stickers) in chunks.enumerate() {
+        let (title, short_name) = if parts_len == 1 {
+            (job.title.clone(), job.short_name.clone())
+        } else {
+            // the chosen short name goes to the first part, the other ones get random short names
+            (
+                part_title(&job.title, index + 1, parts_len),
+                job.short_name.clone().filter(|_| index == 0),
+            )
+        };
+        let short_name = match short_name {
+            Some(short_name) => short_name,
+            None => generate_sticker_set_name_and_link(11, bot_username).0,
+        };
+
+        let part = save_steal_job_part(uow_factory, &job, title, short_name, stickers)
+            .await
+            .map_err(|err| EnqueueError::new(err.to_string()))?;
+
+        match part {
+            Some(part) => parts.push(part),
+            None => {
+                cancel_steal_jobs(uow_factory, &parts)
+                    .await
+                    .map_err(|err| EnqueueError::new(err.to_string()))?;
+
+                return Err(EnqueueError::new("steal job could not be saved"));
+            }
+        }
+    }
+
+    Ok(parts)
+}
+
+/// Create new sticker sets from the saved `parts` of the steal job
+/// and notify the user about all of them when it's done
+pub(super) async fn steal_sticker_set<UoWFactory>(
+    bot: &Scheduler,
+    uow_factory: &UoWFactory,
+    parts: Vec<StealJob>,
+) -> Result<(), HandlerError>
+where
+    UoWFactory: UoWFactoryTrait,
+{
+    let (tg_id, source_title, source_short_name) = match parts.first() {
+        Some(part) => (
+            part.tg_id,
+            part.source_title.clone(),
+            part.source_short_name.clone(),
+        ),
+        None => return Ok(()),
+    };
+
+    // cant panic because bot cant be without username
+    let bot_username = bot
+        .send(GetMe::new())
+        .await?
+        .username
+        .expect("bot without username :/");
+
+    let parts_len = parts.len();
+
+    let mut created_sets = Vec::with_capacity(parts_len);
+    let mut parts = parts.into_iter();
+    while let Some(part) = parts.next() {
+        match create_steal_job_sticker_set(bot, uow_factory, &bot_username, part).await? {
+            Some(created_set) => created_sets.push(created_set),
+            // user is already notified about the error, but the created parts should be reported anyway
+            None => {
+                cancel_steal_jobs(uow_factory, parts.as_slice())
+                    .await
+                    .map_err(HandlerError::new)?;
+                break;
+            }
+        }
+    }
+
+    // merged sticker sets have several sources, so they can't follow them
+    let source_short_name = source_short_name.filter(|name| is_sticker_set_short_name(name));
+    let provenance = provenance_message(
+        source_title.as_deref(),
+        source_short_name.as_deref(),
+        Some(Utc::now()),
+    );
+
+    let mut text = match created_sets.as_slice() {
+        [] => return Ok(()),
+        [created_set] if parts_len == 1 => stolen_sticker_set_message(
+            &created_set.title,
+            &created_set.short_name,
+            &created_set.link,
+            created_set.failed,
+            provenance.as_deref(),
+        ),
+        created_sets => split_sticker_sets_message(
+            &created_sets
+                .iter()
+                .map(|created_set| {
+                    (
+                        created_set.title.as_str(),
+                        created_set.link.as_str(),
+                        created_set.failed,
+                    )
+                })
+                .collect::<Vec<_>>(),
+            provenance.as_deref(),
+        ),
+    };
+
+    for created_set in &created_sets {
+        if let Some(occupied_short_name) = created_set.occupied_short_name.as_deref() {
+            text.push_str(&occupied_short_name_message(
+                occupied_short_name,
+                &created_set.short_name,
+            ));
+        }
+    }
+
+    bot.send(SendMessage::new(tg_id, text).parse_mode(ParseMode::HTML))
+        .await?;
+
+    Ok(())
+}
+
+/// Save the steal job for one sticker set `short_name` with `title` from the `stickers` of the job,
+/// so that it can be resumed if the bot is restarted. Returns `None`, if the job could not be saved.
+async fn save_steal_job_part<UoWFactory>(
+    uow_factory: &UoWFactory,
+    job: &StealStickerSet,
+    title: String,
+    short_name: String,
+    stickers: &[Sticker],
+) -> Result<Option<StealJob>, TransactionKind>
+where
+    UoWFactory: UoWFactoryTrait,
+{
+    let mut uow = uow_factory.create_uow();
+
+    let id = create_steal_job(
+        &mut uow,
+        CreateStealJob::new(
+            StealJobKind::Steal,
+            job.tg_id,
+            Some(job.source_short_name.as_str()),
+            Some(job.source_title.as_str()),
+            short_name.as_str(),
+            title.as_str(),
+            job.sticker_type.as_str(),
+            job.thumbnail.as_ref(),
+            stickers,
+        ),
+    )
+    .await?;
+
+    Ok(id.map(|id| StealJob {
+        id,
+        kind: StealJobKind::Steal,
+        tg_id: job.tg_id,
+        source_short_name: Some(job.source_short_name.clone()),
+        source_title: Some(job.source_title.clone()),
+        short_name,
+        title,
+        sticker_type: job.sticker_type.clone(),
+        thumbnail: job.thumbnail.clone(),
+        stickers: stickers.to_vec(),
+        processed: 0,
+        failed: 0,
+        finished: false,
+    }))
+}
+
+/// Create the sticker set of the saved steal `job` with the stickers that can be added in one go,
+/// and add the rest of them one by one.
+/// Returns `None`, if the sticker set could not be created (user is notified about it).
+async fn create_steal_job_sticker_set<UoWFactory>(
+    bot: &Scheduler,
+    uow_factory: &UoWFactory,
+    bot_username: &str,
+    mut job: StealJob,
+) -> Result<Option<CreatedSet>, HandlerError>
+where
+    UoWFactory: UoWFactoryTrait,
+{
+    let mut uow = uow_factory.create_uow();
+
+    let stickers_len = job.stickers.len() as i32;
+    let limit_sticker_set_length = job
+        .stickers
+        .len()
+        .min(create_set_in_one_go_length_limit(&job.sticker_type));
+
+    let chosen_short_name = job.short_name.clone();
+    let mut alternatives =
+        sticker_set_short_name_alternatives(&chosen_short_name, bot_username).into_iter();
+
+    while let Err(err) = bot
+        .send({
+            let create_set = CreateNewStickerSet::new(
+                job.tg_id,
+                job.short_name.as_str(),
+                job.title.as_str(),
+                job.stickers
+                    .iter()
+                    .take(limit_sticker_set_length)
+                    .map(|sticker| input_sticker(sticker, &job.sticker_type)),
+            );
+
+            match job.sticker_type.as_str() {
+                CUSTOM_EMOJI_STICKER_TYPE => create_set
+                    .sticker_type(CUSTOM_EMOJI_STICKER_TYPE)
+                    // custom emoji of the source set are repainted, so the stolen ones should be too
+                    .needs_repainting(
+                        job.stickers
+                            .iter()
+                            .any(|sticker| sticker.needs_repainting.unwrap_or(false)),
+                    ),
+                MASK_STICKER_TYPE => create_set.sticker_type(MASK_STICKER_TYPE),
+                _ => create_set,
+            }
+        })
+        .await
+    {
+        if matches!(&err, ErrorKind::Telegram(TelegramErrorKind::BadRequest { message })
+            if message.as_ref() == "Bad Request: SHORTNAME_OCCUPY_FAILED")
+        {
+            // the chosen short name could be occupied while the job was in the queue or belong to a deleted
+            // sticker set, so the similar ones are tried first and then the random one
+            job.short_name = alternatives.next().unwrap_or_else(|| {
+                let (short_name, _) = generate_sticker_set_name_and_link(11, bot_username);
+                short_name
+            });
+
+            update_steal_job_short_name(&mut uow, UpdateShortName::new(job.id, &job.short_name))
+                .await
+                .map_err(HandlerError::new)?;
+
+            continue;
+        }
+
+        error!(
+            ?err,
+            ?job.short_name,
+            "Error occurred while creating new sticker set: "
+        );
+
+        // the sticker set can't be created, so there is nothing to resume
+        cancel_steal_jobs(uow_factory, std::slice::from_ref(&job))
+            .await
+            .map_err(HandlerError::new)?;
+
+        bot.send(SendMessage::new(
+            job.tg_id,
+            "Sorry, an error occurred while creating new sticker pack",
+        ))
+        .await?;
+
+        return Ok(None);
+    }
+
+    job.processed = limit_sticker_set_length as i32;
+    update_steal_job_progress(
+        &mut uow,
+        UpdateProgress::new(
+            job.id,
+            job.processed,
+            job.failed,
+            job.processed == stickers_len,
+        ),
+    )
+    .await
+    .map_err(HandlerError::new)?;
+
+    // sticker set without the thumbnail is still fine, so we don't fail the whole steal
+    if let Some(thumbnail) = job.thumbnail.as_ref()
+        && let Err(err) = copy_thumbnail(
+            bot,
+            &job,
+            thumbnail,
+            &job.stickers[..limit_sticker_set_length],
+        )
+        .await
+    {
+        error!(
+            ?err,
+            ?job.short_name,
+            "Error occurred while copying sticker set thumbnail: "
+        );
+    }
+
+    // merged sticker sets have several sources, so they can't follow them
+    let source_short_name = job
+        .source_short_name
+        .as_deref()
+        .filter(|name| is_sticker_set_short_name(name));
+
+    create_set(
+        &mut uow,
+        CreateSet::new(
+            job.tg_id,
+            job.short_name.as_str(),
+            job.title.as_str(),
+            source_short_name,
+            job.source_title.as_deref(),
+            // the rest of the stickers are counted while they're added
+            limit_sticker_set_length as i32,
+        ),
+    )
+    .await
+    .map_err(HandlerError::new)?;
+
+    let failed = add_job_stickers(bot, uow_factory, &job).await?;
+
+    Ok(Some(CreatedSet {
+        link: format!("{TELEGRAM_STICKER_SET_URL}{}", job.short_name),
+        title: job.title,
+        occupied_short_name: (job.short_name != chosen_short_name).then_some(chosen_short_name),
+        short_name: job.short_name,
+        failed,
+    }))
+}
+
+/// Set the `thumbnail` of the source sticker set to the sticker set of the `job`.
+/// Custom emoji sticker sets use one of their custom emoji as a thumbnail, so we look for the copy of it
+/// among the `created_stickers`, that were added while creating the sticker set.
+async fn copy_thumbnail(
+    bot: &Scheduler,
+    job: &StealJob,
+    thumbnail: &PhotoSize,
+    created_stickers: &[Sticker],
+) -> Result<(), SessionErrorKind> {
+    let set_name = job.short_name.as_str();
+
+    if job.sticker_type == CUSTOM_EMOJI_STICKER_TYPE {
+        let index = match created_stickers.iter().position(|sticker| {
+            sticker.thumbnail.as_ref().is_some_and(|sticker_thumbnail| {
+                sticker_thumbnail.file_unique_id == thumbnail.file_unique_id
+            })
+        }) {
+            Some(index) => index,
+            // the first custom emoji is used as a thumbnail by default
+            None => return Ok(()),
+        };
+
+        let new_set = bot.send(GetStickerSet::new(set_name)).await?;
+        let custom_emoji_id = match new_set
+            .stickers
+            .get(index)
+            .and_then(|sticker| sticker.custom_emoji_id.clone())
+        {
+            Some(custom_emoji_id) => custom_emoji_id,
+            None => return Ok(()),
+        };
+
+        bot.send(SetCustomEmojiStickerSetThumbnail::new(set_name).custom_emoji_id(custom_emoji_id))
+            .await?;
+
+        return Ok(());
+    }
+
+    let file = bot.send(GetFile::new(thumbnail.file_id.as_ref())).await?;
+    let format = match file.file_path.as_deref().and_then(thumbnail_format) {
+        Some(format) => format,
+        None => {
+            debug!(?file.file_path, "Unknown format of sticker set thumbnail");
+            return Ok(());
+        }
+    };
+
+    bot.send(
+        SetStickerSetThumbnail::new(set_name, job.tg_id, format.as_str())
+            .thumbnail(InputFile::id(thumbnail.file_id.as_ref())),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Add all the stickers of the job that have not been processed yet into its sticker set
+/// and notify the user when it's done. If the bot was restarted before the sticker set was created, it's created first.
+pub(super) async fn run_steal_job<UoWFactory>(
+    bot: &Scheduler,
+    uow_factory: &UoWFactory,
+    mut job: StealJob,
+) -> Result<(), HandlerError>
+where
+    UoWFactory: UoWFactoryTrait,
+{
+    let mut occupied_short_name = None;
+
+    let failed = match bot.send(GetStickerSet::new(job.short_name.as_str())).await {
+        Err(ErrorKind::Telegram(TelegramErrorKind::BadRequest { message }))
+            if job.processed == 0 && message.as_ref() == "Bad Request: STICKERSET_INVALID" =>
+        {
+            // cant panic because bot cant be without username
+            let bot_username = bot
+                .send(GetMe::new())
+                .await?
+                .username
+                .expect("bot without username :/");
+
+            match create_steal_job_sticker_set(bot, uow_factory, &bot_username, job.clone()).await?
+            {
+                Some(created_set) => {
+                    job.short_name = created_set.short_name;
+                    occupied_short_name = created_set.occupied_short_name;
+                    created_set.failed
+                }
+                // user is already notified about the error
+                None => return Ok(()),
+            }
+        }
+        Err(ErrorKind::Telegram(TelegramErrorKind::BadRequest { message }))
+            if message.as_ref() == "Bad Request: STICKERSET_INVALID" =>
+        {
+            debug!(?job.short_name, "Sticker set was deleted while it was being filled");
+
+            // the user deleted the sticker set, so there is nothing to resume
+            cancel_steal_jobs(uow_factory, std::slice::from_ref(&job))
+                .await
+                .map_err(HandlerError::new)?;
+
+            bot.send(
+                SendMessage::new(
+                    job.tg_id,
+                    format!(
+                        "The sticker pack {title} was deleted before all the stickers were added to it, so I stopped stealing it.",
+                        title = html_quote(&job.title),
+                    ),
+                )
+                .parse_mode(ParseMode::HTML),
+            )
+            .await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            // the job isn't advanced, so it will be resumed after the next restart
+            error!(
+                ?err,
+                ?job.short_name,
+                "Error occurred while getting sticker set of the steal job: "
+            );
+
+            return Ok(());
+        }
+        Ok(sticker_set) => {
+            // merged sticker sets can mix masks with regular stickers, so the type of the created one is used
+            job.sticker_type = sticker_set.sticker_type.to_string();
+
+            if job.processed == 0 {
+                // the sticker set was created, but the bot was restarted before the progress was saved
+                job.processed = sticker_set.stickers.len() as i32;
+
+                let mut uow = uow_factory.create_uow();
+
+                // merged sticker sets have several sources, so they can't follow them
+                let source_short_name = job
+                    .source_short_name
+                    .as_deref()
+                    .filter(|name| is_sticker_set_short_name(name));
+
+                create_set(
+                    &mut uow,
+                    CreateSet::new(
+                        job.tg_id,
+                        job.short_name.as_str(),
+                        job.title.as_str(),
+                        source_short_name,
+                        job.source_title.as_deref(),
+                        job.processed,
+                    ),
+                )
+                .await
+                .map_err(HandlerError::new)?;
+            }
+
+            add_job_stickers(bot, uow_factory, &job).await?
+        }
+    };
+
+    let set_link = format!("{TELEGRAM_STICKER_SET_URL}{}", job.short_name);
+
+    // the provenance is saved with the sticker set, so resumed jobs can show it too
+    let mut uow = uow_factory.create_uow();
+    let provenance = match uow.set_repo().await {
+        Ok(repo) => repo
+            .get_one_by_short_name(GetSetByShortName::new(&job.short_name))
+            .await
+            .ok()
+            .and_then(|set| {
+                provenance_message(
+                    set.source_title.as_deref(),
+                    set.source_short_name.as_deref(),
+                    set.created_at,
+                )
+            }),
+        Err(_) => None,
+    };
+
+    let mut text = stolen_sticker_set_message(
+        &job.title,
+        &job.short_name,
+        &set_link,
+        failed,
+        provenance.as_deref(),
+    );
+    if let Some(occupied_short_name) = occupied_short_name {
+        text.push_str(&occupied_short_name_message(
+            &occupied_short_name,
+            &job.short_name,
+        ));
+    }
+
+    bot.send(SendMessage::new(job.tg_id, text).parse_mode(ParseMode::HTML))
+        .await?;
+
+    Ok(())
+}
+
+fn stolen_sticker_set_message(
+    title: &str,
+    short_name: &str,
+    link: &str,
+    failed: i32,
+    provenance: Option<&str>,
+) -> String {
+    if failed == 0 {
+        return sticker_set_message(title, short_name, link, provenance);
+    }
+
+    format!(
+        "Error occurred while creating new sticker pack {created_pack} but sticker pack was created! \
+        Due to an error, not all stickers have been stolen. The internal name of this sticker pack: {copy_set_name}.",
+        created_pack = html_text_link(html_quote(title), link),
+        copy_set_name = html_code(short_name)
+    )
+}
+
+/// Let the user know, that the sticker set got `short_name`, because the chosen one was occupied
+fn occupied_short_name_message(occupied_short_name: &str, short_name: &str) -> String {
+    format!(
+        "\n\nThe name {occupied_short_name} turned out to be occupied, so the sticker pack got the name {short_name}.",
+        occupied_short_name = html_code(occupied_short_name),
+        short_name = html_code(short_name)
+    )
+}
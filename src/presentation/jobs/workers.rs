@@ -0,0 +1,59 @@
+use sqlx::{Database, Pool};
+use telers::event::simple::HandlerResult;
+use tracing::{debug, error};
+
+use super::{
+    add_stickers::add_stickers,
+    export_sticker_set::export_sticker_set,
+    queue::{Job, JobReceiver},
+    set_sticker_set_emoji::set_sticker_set_emoji,
+    steal_sticker_set::{run_steal_job, steal_sticker_set},
+};
+use crate::{
+    application::{
+        set::repository::SetRepo, steal_job::repository::StealJobRepo, user::repository::UserRepo,
+    },
+    core::helpers::constants::WORKERS_NUMBER,
+    infrastructure::database::{
+        repositories::{set::SetRepoImpl, steal_job::StealJobRepoImpl, user::UserRepoImpl},
+        uow::UoWFactory,
+    },
+    presentation::scheduler::Scheduler,
+};
+
+/// Spawn a pool of workers that take jobs from the queue one by one
+pub async fn run_workers<DB>(pool: Pool<DB>, bot: Scheduler, receiver: JobReceiver) -> HandlerResult
+where
+    DB: Database,
+    for<'a> UserRepoImpl<&'a mut DB::Connection>: UserRepo,
+    for<'a> SetRepoImpl<&'a mut DB::Connection>: SetRepo,
+    for<'a> StealJobRepoImpl<&'a mut DB::Connection>: StealJobRepo,
+{
+    for worker_id in 0..WORKERS_NUMBER {
+        // the scheduler is shared between all workers, so that they don't exceed the Telegram limits together
+        let (bot, receiver) = (bot.clone(), receiver.clone());
+        let uow_factory = UoWFactory::new(pool.clone());
+
+        tokio::spawn(async move {
+            debug!("Worker {worker_id} started.");
+
+            while let Some(job) = receiver.recv().await {
+                let result = match job {
+                    Job::StealStickerSet(job) => steal_sticker_set(&bot, &uow_factory, job).await,
+                    Job::ResumeStealJob(job) => run_steal_job(&bot, &uow_factory, job).await,
+                    Job::AddStickers(job) => add_stickers(&bot, &uow_factory, job).await,
+                    Job::ExportStickerSet(job) => export_sticker_set(&bot, job).await,
+                    Job::SetStickerSetEmoji(job) => set_sticker_set_emoji(&bot, job).await,
+                };
+
+                if let Err(err) = result {
+                    error!(?err, worker_id, "Error occurred while running job: ");
+                }
+            }
+
+            debug!("Worker {worker_id} stopped.");
+        });
+    }
+
+    Ok(())
+}
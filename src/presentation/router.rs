@@ -9,15 +9,31 @@ use telers::{
 use tracing::debug;
 
 use crate::{
+    core::helpers::constants::JOB_QUEUE_CAPACITY,
     infrastructure::database::uow::UoWFactory,
     presentation::{
-        commands::{deleted_sets_upd, init_commands, set_commands},
+        chat_locks::ChatLocks,
+        commands::{
+            deleted_sets_upd, followed_sets_upd, init_commands, resume_steal_jobs, set_commands,
+        },
+        jobs::{JobQueue, JobReceiver, job_queue, run_workers},
         middlewares::CreateUserMiddleware,
+        scheduler::Scheduler,
     },
 };
 
 pub async fn start_bot(bot: &'static Bot, pool: Pool<Postgres>, client: Client) {
-    let router = init_router(bot, pool.clone());
+    let (job_queue, job_receiver) = job_queue(JOB_QUEUE_CAPACITY);
+    // all requests of handlers and background jobs go through the same scheduler to share the Telegram limits
+    let scheduler = Scheduler::new(bot.clone());
+
+    let router = init_router(
+        bot,
+        pool.clone(),
+        scheduler.clone(),
+        job_queue.clone(),
+        job_receiver,
+    );
 
     let dispatcher = Dispatcher::builder()
         .main_router(router.clone().configure_default())
@@ -25,6 +41,9 @@ pub async fn start_bot(bot: &'static Bot, pool: Pool<Postgres>, client: Client)
         .allowed_updates(router.resolve_used_update_types())
         .extension(client)
         .extension(UoWFactory::new(pool))
+        .extension(job_queue)
+        .extension(scheduler)
+        .extension(ChatLocks::new())
         .build();
 
     match dispatcher.run_polling().await {
@@ -33,7 +52,13 @@ pub async fn start_bot(bot: &'static Bot, pool: Pool<Postgres>, client: Client)
     }
 }
 
-fn init_router(bot: &'static Bot, pool: Pool<Postgres>) -> Router {
+fn init_router(
+    bot: &'static Bot,
+    pool: Pool<Postgres>,
+    scheduler: Scheduler,
+    job_queue: JobQueue,
+    job_receiver: JobReceiver,
+) -> Router {
     let mut main_router = Router::new("main");
     let mut private_router = Router::new("private");
 
@@ -55,7 +80,20 @@ fn init_router(bot: &'static Bot, pool: Pool<Postgres>) -> Router {
 
     main_router
         .startup
-        .register(deleted_sets_upd, (pool.clone(), bot.clone()));
+        .register(deleted_sets_upd, (pool.clone(), scheduler.clone()));
+
+    main_router.startup.register(
+        followed_sets_upd,
+        (pool.clone(), scheduler.clone(), job_queue.clone()),
+    );
+
+    main_router
+        .startup
+        .register(run_workers, (pool.clone(), scheduler, job_receiver));
+
+    main_router
+        .startup
+        .register(resume_steal_jobs, (pool.clone(), job_queue));
 
     main_router.include(private_router);
     main_router.startup.register(set_commands, (bot,));
@@ -0,0 +1,214 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use serde::Serialize;
+use telers::{
+    Bot,
+    errors::{SessionErrorKind, TelegramErrorKind},
+    methods::TelegramMethod,
+};
+use tracing::debug;
+
+use crate::core::helpers::{
+    constants::{
+        CHAT_REQUESTS_BURST, CHAT_REQUESTS_PER_SECOND, GLOBAL_REQUESTS_BURST,
+        GLOBAL_REQUESTS_PER_SECOND, MAX_CHAT_BUCKETS, MAX_FLOOD_RETRIES, TELEGRAM_FILE_URL,
+    },
+    token_bucket::TokenBucket,
+};
+
+/// Wrapper around `Bot`, that sends requests through the token buckets shared by all handlers and jobs:
+/// one global, one per chat and one for the requests without a chat.
+/// If Telegram asks to wait anyway, the request is retried after `retry_after`.
+#[derive(Debug, Clone)]
+pub struct Scheduler {
+    bot: Bot,
+    /// Client to download files, they are not sent through the Bot API methods
+    http_client: reqwest::Client,
+    buckets: Arc<Mutex<Buckets>>,
+}
+
+/// Error occurred while downloading the file. The URL of the file contains the bot token,
+/// so it's removed from the error to not leak the token into the logs and messages.
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct DownloadError(reqwest::Error);
+
+impl From<reqwest::Error> for DownloadError {
+    fn from(err: reqwest::Error) -> Self {
+        Self(err.without_url())
+    }
+}
+
+#[derive(Debug)]
+struct Buckets {
+    global: TokenBucket,
+    /// Requests without a chat (`GetStickerSet`, `GetFile`, sticker edits), so the flood limit of them
+    /// pauses only each other instead of all requests
+    unowned: TokenBucket,
+    chats: HashMap<i64, TokenBucket>,
+}
+
+impl Buckets {
+    fn new(now: Instant) -> Self {
+        Self {
+            global: TokenBucket::new(GLOBAL_REQUESTS_BURST, GLOBAL_REQUESTS_PER_SECOND, now),
+            unowned: TokenBucket::new(GLOBAL_REQUESTS_BURST, GLOBAL_REQUESTS_PER_SECOND, now),
+            chats: HashMap::new(),
+        }
+    }
+
+    /// Bucket of the chat or the bucket of the requests without a chat
+    fn bucket(&mut self, chat_id: Option<i64>, now: Instant) -> &mut TokenBucket {
+        match chat_id {
+            Some(chat_id) => self.chats.entry(chat_id).or_insert_with(|| {
+                TokenBucket::new(CHAT_REQUESTS_BURST, CHAT_REQUESTS_PER_SECOND, now)
+            }),
+            None => &mut self.unowned,
+        }
+    }
+
+    /// Take a token from the global bucket and the bucket of the chat (or the requests without a chat) at once,
+    /// otherwise returns how long to wait until both of them have one
+    fn try_take(&mut self, chat_id: Option<i64>, now: Instant) -> Option<Duration> {
+        if self.chats.len() > MAX_CHAT_BUCKETS {
+            self.chats.retain(|_, bucket| !bucket.is_full(now));
+        }
+
+        let wait_time = self
+            .global
+            .wait_time(now)
+            .max(self.bucket(chat_id, now).wait_time(now));
+
+        if wait_time.is_none() {
+            self.global.take();
+            self.bucket(chat_id, now).take();
+        }
+
+        wait_time
+    }
+}
+
+impl Scheduler {
+    pub fn new(bot: Bot) -> Self {
+        let now = Instant::now();
+
+        Self {
+            bot,
+            http_client: reqwest::Client::new(),
+            buckets: Arc::new(Mutex::new(Buckets::new(now))),
+        }
+    }
+
+    /// Send the request, when the limits allow it. The chat of the request is taken from its
+    /// `chat_id` or `user_id` (sticker set methods), requests without them share their own bucket.
+    pub async fn send<T>(&self, method: T) -> Result<T::Return, SessionErrorKind>
+    where
+        T: TelegramMethod + Serialize + Clone + Send + Sync,
+        T::Method: Send + Sync,
+    {
+        let chat_id = method_chat_id(&method);
+        let mut retries = 0;
+
+        loop {
+            self.acquire(chat_id).await;
+
+            match self.bot.send(method.clone()).await {
+                Err(SessionErrorKind::Telegram(TelegramErrorKind::RetryAfter {
+                    retry_after,
+                    ..
+                })) if retries < MAX_FLOOD_RETRIES => {
+                    let retry_after = Duration::from_secs(retry_after as u64);
+
+                    debug!(
+                        ?chat_id,
+                        ?retry_after,
+                        retries,
+                        "Flood limit exceeded, retrying the request.."
+                    );
+
+                    self.pause(chat_id, retry_after);
+                    retries += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Download the file by its `file_path` from `GetFile`. Telegram allows to download files up to 20 MB.
+    /// The files are downloaded not through the Bot API methods, so the limits don't apply to them.
+    pub async fn download_file(&self, file_path: &str) -> Result<Vec<u8>, DownloadError> {
+        let response = self
+            .http_client
+            .get(format!(
+                "{TELEGRAM_FILE_URL}{token}/{file_path}",
+                token = self.bot.token
+            ))
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    async fn acquire(&self, chat_id: Option<i64>) {
+        loop {
+            // only panic if other thread panicked while holding the lock, but we don't panic there
+            let wait_time = self
+                .buckets
+                .lock()
+                .unwrap()
+                .try_take(chat_id, Instant::now());
+
+            match wait_time {
+                Some(wait_time) => tokio::time::sleep(wait_time).await,
+                None => return,
+            }
+        }
+    }
+
+    /// Don't send requests to the chat (or requests without a chat, if there is no chat) for `duration`
+    fn pause(&self, chat_id: Option<i64>, duration: Duration) {
+        let now = Instant::now();
+
+        // only panic if other thread panicked while holding the lock, but we don't panic there
+        self.buckets
+            .lock()
+            .unwrap()
+            .bucket(chat_id, now)
+            .pause(now + duration);
+    }
+}
+
+fn method_chat_id(method: &impl Serialize) -> Option<i64> {
+    let method = serde_json::to_value(method).ok()?;
+
+    method
+        .get("chat_id")
+        .or_else(|| method.get("user_id"))
+        .and_then(|id| id.as_i64())
+}
+
+#[test]
+fn buckets_pause_test() {
+    let now = Instant::now();
+    let mut buckets = Buckets::new(now);
+
+    // flood limit of the requests without a chat doesn't stop the requests to the chats
+    buckets
+        .bucket(None, now)
+        .pause(now + Duration::from_secs(10));
+    assert!(buckets.try_take(None, now).is_some());
+    assert_eq!(buckets.try_take(Some(1), now), None);
+
+    // and flood limit of the chat doesn't stop the requests without a chat and to other chats
+    buckets
+        .bucket(Some(1), now)
+        .pause(now + Duration::from_secs(10));
+    assert!(buckets.try_take(Some(1), now).is_some());
+    assert_eq!(buckets.try_take(Some(2), now), None);
+    assert_eq!(buckets.try_take(None, now + Duration::from_secs(11)), None);
+}